@@ -8,7 +8,7 @@ use clap::{App, Arg, ArgMatches};
 use daap7::{
     problem_solver::{
         grasp::{local_search::*, stop_criterion::TotalIterations},
-        ProblemSolver, GRASP,
+        ProblemSolver, ReactiveGRASP, GRASP,
     },
     ProblemInstance,
 };
@@ -42,6 +42,7 @@ fn main() -> std::io::Result<()> {
     for iterations in vec![100, 500, 1_000, 2_000] {
         let solver_list: Vec<(String, Box<dyn ProblemSolver>)> = get_solver_list(iterations);
         print_results(&instance, &mut output_file, solver_list)?;
+        print_reactive_result(&instance, &mut output_file, iterations)?;
     }
     Ok(())
 }
@@ -136,6 +137,20 @@ fn get_solver_list(iterations: usize) -> Vec<(String, Box<dyn ProblemSolver>)> {
                 TotalIterations::new(iterations),
             )),
         ));
+        let environments: Vec<Box<dyn LocalSearch>> = vec![
+            Box::new(IntraMachineSwap::new()),
+            Box::new(IntraMachineReinsertion::new()),
+            Box::new(InterMachineSwap::new()),
+            Box::new(InterMachineReinsertion::new()),
+        ];
+        list.push((
+            format!("{},GRASP k={} VND,", iterations, k),
+            Box::new(GRASP::new(
+                k,
+                Vnd::new(environments),
+                TotalIterations::new(iterations),
+            )),
+        ));
     }
     list
 }
@@ -163,6 +178,39 @@ fn print_result(
     write!(output_file, "{},{}\n", tct, duration.as_millis())
 }
 
+/// Runs a Reactive GRASP with the same k values as the GRASPs. After its result it writes
+/// a row for each k with the probability it has learned in the tct column and no time
+fn print_reactive_result(
+    instance: &ProblemInstance,
+    output_file: &mut File,
+    iterations: usize,
+) -> Result<()> {
+    let mut solver = ReactiveGRASP::new(
+        vec![2, 3],
+        10,
+        8.0,
+        InterMachineReinsertion::new(),
+        TotalIterations::new(iterations),
+    );
+    write!(
+        output_file,
+        "{},Reactive GRASP InterMachineReinsertion,",
+        iterations
+    )?;
+    let instant = Instant::now();
+    let tct = solver.solve(instance).get_total_completion_time();
+    let duration = instant.elapsed();
+    writeln!(output_file, "{},{}", tct, duration.as_millis())?;
+    for (k, probability) in solver.get_probabilities() {
+        writeln!(
+            output_file,
+            "{},Reactive GRASP InterMachineReinsertion P(k={}),{:.3},",
+            iterations, k, probability
+        )?;
+    }
+    Ok(())
+}
+
 fn print_headers(file: &mut File) -> Result<()> {
     write!(file, "iterations,")?;
     write!(file, "algorithm,")?;
@@ -8,7 +8,8 @@ use clap::{App, Arg, ArgMatches};
 use daap7::{
     problem_solver::{
         grasp::{local_search::*, stop_criterion::TotalIterations},
-        ProblemSolver, GVNS,
+        gvns::shake::*,
+        InitialSolution, ProblemSolver, VndVariant, GRASP, GVNS,
     },
     ProblemInstance,
 };
@@ -66,38 +67,90 @@ fn get_args() -> ArgMatches<'static> {
 fn get_solver_list(iterations: usize) -> Vec<(String, Box<dyn ProblemSolver>)> {
     let mut list: Vec<(String, Box<dyn ProblemSolver>)> = Vec::new();
     for k_max in vec![3, 4, 5] {
-        let environments: Vec<Box<dyn LocalSearch>> = vec![
-            Box::new(IntraMachineSwap::new()),
-            Box::new(IntraMachineReinsertion::new()),
-            Box::new(InterMachineSwap::new()),
-            Box::new(InterMachineReinsertion::new()),
-        ];
+        for (vnd_variant, name) in [
+            (VndVariant::Basic, "basic VND"),
+            (VndVariant::Pipe, "pipe VND"),
+            (VndVariant::Cyclic, "cyclic VND"),
+            (VndVariant::Union, "union VND"),
+        ] {
+            list.push((
+                format!("{},GVNS {} k_max={},", iterations, name, k_max),
+                Box::new(GVNS::new(
+                    k_max,
+                    TotalIterations::new(iterations),
+                    get_shakes(),
+                    get_environments(),
+                    vnd_variant,
+                    get_initial_solution(),
+                )),
+            ));
+        }
         list.push((
-            format!("{},GVNS k_max={},", iterations, k_max),
+            format!("{},GVNS mixed shakes k_max={},", iterations, k_max),
             Box::new(GVNS::new(
                 k_max,
                 TotalIterations::new(iterations),
-                environments,
+                get_mixed_shakes(),
+                get_environments(),
+                VndVariant::Basic,
+                get_initial_solution(),
             )),
         ));
-        let anxious_environments: Vec<Box<dyn LocalSearch>> = vec![
-            Box::new(IntraMachineSwapAnxious::new()),
-            Box::new(IntraMachineReinsertionAnxious::new()),
-            Box::new(InterMachineSwapAnxious::new()),
-            Box::new(InterMachineReinsertionAnxious::new()),
-        ];
         list.push((
             format!("{},GVNS anxious k_max={},", iterations, k_max),
             Box::new(GVNS::new(
                 k_max,
                 TotalIterations::new(iterations),
-                anxious_environments,
+                get_shakes(),
+                get_anxious_environments(),
+                VndVariant::Basic,
+                get_initial_solution(),
             )),
         ));
     }
     list
 }
 
+fn get_initial_solution() -> InitialSolution {
+    InitialSolution::Solver(Box::new(GRASP::new(
+        2,
+        InterMachineReinsertion::new(),
+        TotalIterations::new(1),
+    )))
+}
+
+fn get_shakes() -> Vec<Box<dyn Shake>> {
+    vec![Box::new(RandomReinsertion::new())]
+}
+
+fn get_mixed_shakes() -> Vec<Box<dyn Shake>> {
+    vec![
+        Box::new(RandomIntraMove::new()),
+        Box::new(RandomSwap::new()),
+        Box::new(RandomReinsertion::new()),
+        Box::new(SegmentScramble::new()),
+        Box::new(MachineEmptying::new()),
+    ]
+}
+
+fn get_environments() -> Vec<Box<dyn LocalSearch>> {
+    vec![
+        Box::new(IntraMachineSwap::new()),
+        Box::new(IntraMachineReinsertion::new()),
+        Box::new(InterMachineSwap::new()),
+        Box::new(InterMachineReinsertion::new()),
+    ]
+}
+
+fn get_anxious_environments() -> Vec<Box<dyn LocalSearch>> {
+    vec![
+        Box::new(IntraMachineSwapAnxious::new()),
+        Box::new(IntraMachineReinsertionAnxious::new()),
+        Box::new(InterMachineSwapAnxious::new()),
+        Box::new(InterMachineReinsertionAnxious::new()),
+    ]
+}
+
 fn print_results(
     instance: &ProblemInstance,
     output_file: &mut File,
@@ -0,0 +1,192 @@
+use super::{
+    grasp::{local_search::LocalSearch, stop_criterion::StopCriterion},
+    ProblemInstance, ProblemSolution, ProblemSolver,
+};
+
+/// A MAX-MIN ant system implementation. In each iteration every ant builds a schedule
+/// appending in each step a task to the end of a machine. The pair is chosen randomly with
+/// a probability proportional to (τs · τm)^α · η^β, where τs is the pheromone of going from
+/// the last task of the machine to the task, τm the pheromone of assigning the task to the
+/// machine and η the inverse of the TCT increment of the append, which depends on the setup
+/// and processing times.
+///
+/// The best ant of the iteration is improved with the local search (use
+/// [NoSearch](super::grasp::local_search::NoSearch) to skip it) and it's the only one
+/// that deposits pheromone, after the evaporation. The pheromone is kept between the
+/// MAX-MIN bounds, that are updated each time the best solution improves. The stop criterion
+/// is checked after each iteration with the best TCT before and after it
+pub struct ACO<L: LocalSearch, S: StopCriterion> {
+    number_of_ants: usize,
+    alpha: f64,
+    beta: f64,
+    evaporation: f64,
+    local_search: L,
+    stop_criterion: S,
+}
+
+/// The pheromone trails of the [ACO](ACO)
+struct Pheromone {
+    // Indexed like the setup times, 0 is the inactive state and task + 1 the task
+    sequence: Vec<Vec<f64>>,
+    machine: Vec<Vec<f64>>,
+    min: f64,
+    max: f64,
+}
+
+impl<L: LocalSearch, S: StopCriterion> ProblemSolver for ACO<L, S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut best_solution = self.iteration_best(instance, &Pheromone::new(instance, 1.0));
+        let mut pheromone = Pheromone::new(
+            instance,
+            self.max_pheromone(best_solution.get_total_completion_time()),
+        );
+        loop {
+            let previous_best_tct = best_solution.get_total_completion_time();
+            let iteration_best = self.iteration_best(instance, &pheromone);
+            let iteration_best_tct = iteration_best.get_total_completion_time();
+            if iteration_best_tct < previous_best_tct {
+                best_solution = iteration_best.clone();
+                pheromone.set_bounds(instance, self.max_pheromone(iteration_best_tct));
+            }
+            pheromone.update(&iteration_best, self.evaporation);
+            if self
+                .stop_criterion
+                .stop(previous_best_tct, best_solution.get_total_completion_time())
+            {
+                return best_solution;
+            }
+        }
+    }
+}
+
+impl<L: LocalSearch, S: StopCriterion> ACO<L, S> {
+    /// Creates a new instance with the specified arguments. There must be at least one ant,
+    /// alpha and beta can't be negative and the evaporation must be between 0 and 1
+    pub fn new(
+        number_of_ants: usize,
+        alpha: f64,
+        beta: f64,
+        evaporation: f64,
+        local_search: L,
+        stop_criterion: S,
+    ) -> Self {
+        assert!(number_of_ants > 0);
+        assert!(alpha >= 0.0 && beta >= 0.0);
+        assert!(evaporation > 0.0 && evaporation < 1.0);
+        ACO {
+            number_of_ants,
+            alpha,
+            beta,
+            evaporation,
+            local_search,
+            stop_criterion,
+        }
+    }
+
+    fn max_pheromone(&self, best_tct: usize) -> f64 {
+        1.0 / (self.evaporation * best_tct as f64)
+    }
+
+    /// Builds the solutions of all the ants and improves the best one with the local search
+    fn iteration_best(&self, instance: &ProblemInstance, pheromone: &Pheromone) -> ProblemSolution {
+        let best_ant = (0..self.number_of_ants)
+            .map(|_| self.build_solution(instance, pheromone))
+            .min_by_key(ProblemSolution::get_total_completion_time)
+            .unwrap();
+        self.local_search.improve(instance, best_ant)
+    }
+
+    fn build_solution(&self, instance: &ProblemInstance, pheromone: &Pheromone) -> ProblemSolution {
+        let number_of_tasks = instance.task_times().len();
+        let number_of_machines = instance.number_of_machines();
+        let mut task_assignment_matrix = vec![Vec::new(); number_of_machines];
+        let mut completion_times = vec![0; number_of_machines];
+        let mut unassigned_tasks: Vec<usize> = (0..number_of_tasks).collect();
+        let mut weights = Vec::with_capacity(number_of_tasks * number_of_machines);
+        while !unassigned_tasks.is_empty() {
+            weights.clear();
+            for &task in &unassigned_tasks {
+                for (machine, tasks) in task_assignment_matrix.iter().enumerate() {
+                    let last = tasks.last().map_or(0, |&last: &usize| last + 1);
+                    let tct_increment = completion_times[machine]
+                        + instance.setup_times()[last][task + 1]
+                        + instance.task_times()[task];
+                    let trail = pheromone.sequence[last][task] * pheromone.machine[task][machine];
+                    weights.push(
+                        trail.powf(self.alpha) * (1.0 / (tct_increment + 1) as f64).powf(self.beta),
+                    );
+                }
+            }
+            let chosen = choose(&weights);
+            let task = unassigned_tasks.swap_remove(chosen / number_of_machines);
+            let machine = chosen % number_of_machines;
+            let last = task_assignment_matrix[machine]
+                .last()
+                .map_or(0, |&last| last + 1);
+            completion_times[machine] +=
+                instance.setup_times()[last][task + 1] + instance.task_times()[task];
+            task_assignment_matrix[machine].push(task);
+        }
+        ProblemSolution::new_unchecked(instance, task_assignment_matrix)
+    }
+}
+
+impl Pheromone {
+    fn new(instance: &ProblemInstance, max: f64) -> Self {
+        let number_of_tasks = instance.task_times().len();
+        Pheromone {
+            sequence: vec![vec![max; number_of_tasks]; number_of_tasks + 1],
+            machine: vec![vec![max; instance.number_of_machines()]; number_of_tasks],
+            min: max / (2 * number_of_tasks) as f64,
+            max,
+        }
+    }
+
+    fn set_bounds(&mut self, instance: &ProblemInstance, max: f64) {
+        self.max = max;
+        self.min = max / (2 * instance.task_times().len()) as f64;
+    }
+
+    /// Evaporates all the trails and deposits pheromone in the ones used by the solution
+    fn update(&mut self, solution: &ProblemSolution, evaporation: f64) {
+        for trail in self
+            .sequence
+            .iter_mut()
+            .chain(self.machine.iter_mut())
+            .flatten()
+        {
+            *trail *= 1.0 - evaporation;
+        }
+        let deposit = 1.0 / solution.get_total_completion_time() as f64;
+        for (machine, tasks) in solution.task_assignment_matrix.iter().enumerate() {
+            let mut last = 0;
+            for &task in tasks {
+                self.sequence[last][task] += deposit;
+                self.machine[task][machine] += deposit;
+                last = task + 1;
+            }
+        }
+        let (min, max) = (self.min, self.max);
+        for trail in self
+            .sequence
+            .iter_mut()
+            .chain(self.machine.iter_mut())
+            .flatten()
+        {
+            *trail = trail.clamp(min, max);
+        }
+    }
+}
+
+/// Chooses an index randomly with a probability proportional to its weight
+fn choose(weights: &[f64]) -> usize {
+    let total: f64 = weights.iter().sum();
+    let mut value = rand::random::<f64>() * total;
+    for (index, weight) in weights.iter().enumerate() {
+        if value < *weight {
+            return index;
+        }
+        value -= weight;
+    }
+    weights.len() - 1
+}
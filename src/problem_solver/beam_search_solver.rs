@@ -0,0 +1,69 @@
+use super::{GreedySolver, ProblemInstance, ProblemSolution, ProblemSolver, Seeding};
+use std::collections::HashSet;
+
+/// A beam search that creates a solution for the problem adding a task in each step, like
+/// the [GreedySolver](super::GreedySolver), but keeping several partial schedules. In each
+/// step every partial schedule is extended with its best insertions (the branching factor)
+/// and only the partial schedules with the lowest TCT are kept (the beam width). The first
+/// task of each machine is chosen with the [seeding](super::Seeding)
+pub struct BeamSearchSolver {
+    beam_width: usize,
+    branching_factor: usize,
+    seeding: Seeding,
+}
+
+impl ProblemSolver for BeamSearchSolver {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut asigned_tasks = HashSet::with_capacity(instance.task_times().len());
+        let mut solution = ProblemSolution {
+            tcts_by_machine: Vec::with_capacity(instance.number_of_machines()),
+            task_assignment_matrix: Vec::with_capacity(instance.number_of_machines()),
+        };
+        self.seeding
+            .seed(instance, &mut solution, &mut asigned_tasks);
+        let mut beam = vec![(solution, asigned_tasks)];
+        while beam[0].1.len() < instance.task_times().len() {
+            let mut children: Vec<(ProblemSolution, HashSet<usize>)> = beam
+                .iter()
+                .flat_map(|(solution, asigned_tasks)| {
+                    GreedySolver::get_best_new_tasks(
+                        solution,
+                        instance,
+                        asigned_tasks,
+                        self.branching_factor,
+                    )
+                    .into_iter()
+                    .map(move |new_task| {
+                        let mut solution = solution.clone();
+                        let mut asigned_tasks = asigned_tasks.clone();
+                        GreedySolver::insert_task(&mut solution, &mut asigned_tasks, &new_task);
+                        (solution, asigned_tasks)
+                    })
+                })
+                .collect();
+            children.sort_by_key(|(solution, _)| solution.get_total_completion_time());
+            children.truncate(self.beam_width);
+            beam = children;
+        }
+        beam.swap_remove(0).0
+    }
+}
+
+impl BeamSearchSolver {
+    /// Creates a new solver with the specified beam width and branching factor, both of
+    /// them must be greater than 0
+    pub fn new(beam_width: usize, branching_factor: usize) -> Self {
+        BeamSearchSolver::with_seeding(beam_width, branching_factor, Seeding::CheapestFirst)
+    }
+
+    /// Creates a new solver with the specified beam width and branching factor that
+    /// chooses the first task of each machine with the seeding
+    pub fn with_seeding(beam_width: usize, branching_factor: usize, seeding: Seeding) -> Self {
+        assert!(beam_width > 0 && branching_factor > 0);
+        BeamSearchSolver {
+            beam_width,
+            branching_factor,
+            seeding,
+        }
+    }
+}
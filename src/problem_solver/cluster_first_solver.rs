@@ -0,0 +1,188 @@
+use super::{ProblemInstance, ProblemSolution, ProblemSolver};
+
+/// A cluster first, sequence second algorithm. First it partitions the tasks in as many
+/// groups as machines with k-medoids, using as the distance between two tasks the mean of
+/// the setup times between them in both directions. Each task goes to the group of its
+/// nearest medoid that has room for it, being the room of a group the mean processing time
+/// per machine times the balance factor, so the groups are balanced by processing time.
+/// Then each group is sequenced in its own machine with the [sequencing](Sequencing) method
+pub struct ClusterFirstSolver {
+    balance_factor: f64,
+    sequencing: Sequencing,
+}
+
+/// The way in which the [ClusterFirstSolver](ClusterFirstSolver) sequences the tasks of
+/// each group
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Sequencing {
+    /// Starting from the inactive state, appends the task with the lowest setup time plus
+    /// processing time after the last one
+    NearestNeighbour,
+    /// Inserts the tasks, in the order in which they were assigned to the group, in the
+    /// position of the machine with the lowest TCT
+    BestInsertion,
+}
+
+/// The maximum number of times the medoids are recalculated
+const MAX_ITERATIONS: usize = 20;
+
+impl ProblemSolver for ClusterFirstSolver {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let distances = ClusterFirstSolver::get_distances(instance);
+        let mut medoids = ClusterFirstSolver::initial_medoids(instance, &distances);
+        let mut groups = self.assign(instance, &distances, &medoids);
+        for _ in 0..MAX_ITERATIONS {
+            let new_medoids: Vec<usize> = groups
+                .iter()
+                .take(medoids.len())
+                .map(|group| ClusterFirstSolver::get_medoid(&distances, group))
+                .collect();
+            if new_medoids == medoids {
+                break;
+            }
+            medoids = new_medoids;
+            groups = self.assign(instance, &distances, &medoids);
+        }
+        let task_assignment_matrix = groups
+            .iter()
+            .map(|group| self.sequencing.sequence(instance, group))
+            .collect();
+        ProblemSolution::new_unchecked(instance, task_assignment_matrix)
+    }
+}
+
+impl ClusterFirstSolver {
+    /// Creates a new solver with the specified arguments. The balance factor must be at
+    /// least 1, the higher it is the less balanced the groups can be
+    pub fn new(balance_factor: f64, sequencing: Sequencing) -> Self {
+        assert!(balance_factor >= 1.0);
+        ClusterFirstSolver {
+            balance_factor,
+            sequencing,
+        }
+    }
+
+    /// Returns the symmetrised setup times between the tasks
+    fn get_distances(instance: &ProblemInstance) -> Vec<Vec<usize>> {
+        let setup_times = instance.setup_times();
+        let number_of_tasks = instance.task_times().len();
+        (0..number_of_tasks)
+            .map(|from| {
+                (0..number_of_tasks)
+                    .map(|to| (setup_times[from + 1][to + 1] + setup_times[to + 1][from + 1]) / 2)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The first medoid is the task with the lowest setup time from the inactive state plus
+    /// processing time, and each of the next ones is the farthest task from the chosen ones
+    fn initial_medoids(instance: &ProblemInstance, distances: &[Vec<usize>]) -> Vec<usize> {
+        let number_of_tasks = instance.task_times().len();
+        let number_of_groups = instance.number_of_machines().min(number_of_tasks);
+        let first = (0..number_of_tasks)
+            .min_by_key(|&task| instance.setup_times()[0][task + 1] + instance.task_times()[task]);
+        let mut medoids: Vec<usize> = first.into_iter().collect();
+        while medoids.len() < number_of_groups {
+            let farthest = (0..number_of_tasks)
+                .filter(|task| !medoids.contains(task))
+                .max_by_key(|&task| {
+                    medoids
+                        .iter()
+                        .map(|&medoid| distances[medoid][task])
+                        .min()
+                        .unwrap()
+                })
+                .unwrap();
+            medoids.push(farthest);
+        }
+        medoids
+    }
+
+    /// Assigns each task to the nearest medoid whose group has room for it, or to the group
+    /// with the lowest processing time if none has. The tasks nearer to a medoid are
+    /// assigned first and each group starts with its medoid
+    fn assign(
+        &self,
+        instance: &ProblemInstance,
+        distances: &[Vec<usize>],
+        medoids: &[usize],
+    ) -> Vec<Vec<usize>> {
+        let task_times = instance.task_times();
+        let room = task_times.iter().sum::<usize>() as f64 / instance.number_of_machines() as f64
+            * self.balance_factor;
+        let mut groups: Vec<Vec<usize>> = medoids.iter().map(|&medoid| vec![medoid]).collect();
+        groups.resize(instance.number_of_machines(), Vec::new());
+        let mut loads: Vec<usize> = groups
+            .iter()
+            .map(|group| group.iter().map(|&task| task_times[task]).sum())
+            .collect();
+        let mut tasks: Vec<usize> = (0..task_times.len())
+            .filter(|task| !medoids.contains(task))
+            .collect();
+        tasks.sort_by_key(|&task| medoids.iter().map(|&medoid| distances[medoid][task]).min());
+        for task in tasks {
+            let mut nearest_medoids: Vec<usize> = (0..medoids.len()).collect();
+            nearest_medoids.sort_by_key(|&group| distances[medoids[group]][task]);
+            let group = nearest_medoids
+                .into_iter()
+                .find(|&group| (loads[group] + task_times[task]) as f64 <= room)
+                .unwrap_or_else(|| (0..loads.len()).min_by_key(|&group| loads[group]).unwrap());
+            groups[group].push(task);
+            loads[group] += task_times[task];
+        }
+        groups
+    }
+
+    /// Returns the task of the group with the lowest sum of distances to the others
+    fn get_medoid(distances: &[Vec<usize>], group: &[usize]) -> usize {
+        *group
+            .iter()
+            .min_by_key(|&&medoid| {
+                group
+                    .iter()
+                    .map(|&task| distances[medoid][task])
+                    .sum::<usize>()
+            })
+            .unwrap()
+    }
+}
+
+impl Sequencing {
+    /// Returns the tasks of the group in the order in which they should be processed
+    fn sequence(&self, instance: &ProblemInstance, group: &[usize]) -> Vec<usize> {
+        match self {
+            Sequencing::NearestNeighbour => {
+                let mut remaining_tasks = group.to_vec();
+                let mut sequence = Vec::with_capacity(group.len());
+                let mut last = 0;
+                while !remaining_tasks.is_empty() {
+                    let index = (0..remaining_tasks.len())
+                        .min_by_key(|&index| {
+                            let task = remaining_tasks[index];
+                            instance.setup_times()[last][task + 1] + instance.task_times()[task]
+                        })
+                        .unwrap();
+                    let task = remaining_tasks.swap_remove(index);
+                    sequence.push(task);
+                    last = task + 1;
+                }
+                sequence
+            }
+            Sequencing::BestInsertion => {
+                let mut sequence = Vec::with_capacity(group.len());
+                for &task in group {
+                    let position = (0..=sequence.len())
+                        .min_by_key(|&position| {
+                            let mut task_list = sequence.clone();
+                            task_list.insert(position, task);
+                            instance.calculate_total_completion_time(&task_list)
+                        })
+                        .unwrap();
+                    sequence.insert(position, task);
+                }
+                sequence
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use super::{ProblemInstance, ProblemSolution, ProblemSolver};
+
+/// A list scheduling algorithm that creates a solution for the problem by taking in each
+/// step the machine that becomes free first and appending to it the task chosen by a
+/// [dispatching rule](DispatchingRule), that only depends on the last task of that machine.
+/// It takes O(n · (n + m)) time, so it can be used with very large instances
+pub struct DispatchingRuleSolver {
+    rule: DispatchingRule,
+}
+
+/// The rule with which the [DispatchingRuleSolver](DispatchingRuleSolver) chooses the next
+/// task of a machine
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DispatchingRule {
+    /// Shortest processing time with setup: the task with the lowest setup time plus
+    /// processing time
+    ShortestProcessingTime,
+    /// Nearest neighbour: the task with the lowest setup time, breaking ties with the
+    /// lowest processing time
+    NearestSetup,
+    /// Apparent tardiness cost with setups: the task with the highest priority
+    /// (1 / p) · e^(-s / (k · mean setup time)). As the instances don't have due dates all
+    /// the tasks are considered due, so the slack factor of the original rule is always 1.
+    /// The scaling parameter k must be greater than 0
+    ATCS(f64),
+}
+
+impl ProblemSolver for DispatchingRuleSolver {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let number_of_tasks = instance.task_times().len();
+        let mean_setup_time = DispatchingRuleSolver::mean_setup_time(instance);
+        let mut task_assignment_matrix = vec![Vec::new(); instance.number_of_machines()];
+        let mut completion_times = vec![0; instance.number_of_machines()];
+        let mut unassigned_tasks: Vec<usize> = (0..number_of_tasks).collect();
+        while !unassigned_tasks.is_empty() {
+            let machine = (0..completion_times.len())
+                .min_by_key(|&machine| completion_times[machine])
+                .unwrap();
+            let last = task_assignment_matrix[machine]
+                .last()
+                .map_or(0, |&last: &usize| last + 1);
+            let index = (0..unassigned_tasks.len())
+                .min_by(|&first, &second| {
+                    self.rule
+                        .cost(instance, last, unassigned_tasks[first], mean_setup_time)
+                        .partial_cmp(&self.rule.cost(
+                            instance,
+                            last,
+                            unassigned_tasks[second],
+                            mean_setup_time,
+                        ))
+                        .unwrap()
+                })
+                .unwrap();
+            let task = unassigned_tasks.swap_remove(index);
+            completion_times[machine] +=
+                instance.setup_times()[last][task + 1] + instance.task_times()[task];
+            task_assignment_matrix[machine].push(task);
+        }
+        ProblemSolution::new_unchecked(instance, task_assignment_matrix)
+    }
+}
+
+impl DispatchingRuleSolver {
+    /// Creates a new solver that will use the specified rule
+    pub fn new(rule: DispatchingRule) -> Self {
+        if let DispatchingRule::ATCS(k) = rule {
+            assert!(k > 0.0);
+        }
+        DispatchingRuleSolver { rule }
+    }
+
+    fn mean_setup_time(instance: &ProblemInstance) -> f64 {
+        let setup_times = instance.setup_times();
+        let total: usize = setup_times.iter().flatten().sum();
+        total as f64 / (setup_times.len() * setup_times.len()) as f64
+    }
+}
+
+impl DispatchingRule {
+    /// Returns the cost of putting the task after the last one, the lower the better. The
+    /// second value only breaks ties. The last task is indexed like in the setup times matrix
+    fn cost(
+        &self,
+        instance: &ProblemInstance,
+        last: usize,
+        task: usize,
+        mean_setup_time: f64,
+    ) -> (f64, f64) {
+        let setup_time = instance.setup_times()[last][task + 1] as f64;
+        let task_time = instance.task_times()[task] as f64;
+        match self {
+            DispatchingRule::ShortestProcessingTime => (setup_time + task_time, 0.0),
+            DispatchingRule::NearestSetup => (setup_time, task_time),
+            DispatchingRule::ATCS(k) => {
+                // The priority is negated, so the highest priority has the lowest cost
+                let priority =
+                    (-setup_time / (k * mean_setup_time.max(1.0))).exp() / task_time.max(1.0);
+                (-priority, 0.0)
+            }
+        }
+    }
+}
@@ -1,10 +1,13 @@
-use super::{NewTask, ProblemInstance, ProblemSolution, ProblemSolver};
+use super::{GreedySolver, NewTask, ProblemInstance, ProblemSolution, ProblemSolver, Seeding};
 use std::collections::HashSet;
 
 /// A greedy algorithm that creates a solution for the problem by evaluating
 /// in each step the best machine to add a determined task to the
-/// solution. It always adds the new tasks at the end, unlike [GreedySolver](super::GreedySolver)
-pub struct FastGreedySolver {}
+/// solution. It always adds the new tasks at the end, unlike [GreedySolver](super::GreedySolver).
+/// The first task of each machine is chosen with the [seeding](super::Seeding)
+pub struct FastGreedySolver {
+    seeding: Seeding,
+}
 
 impl ProblemSolver for FastGreedySolver {
     fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
@@ -13,7 +16,8 @@ impl ProblemSolver for FastGreedySolver {
             tcts_by_machine: Vec::with_capacity(instance.number_of_machines()),
             task_assignment_matrix: Vec::with_capacity(instance.number_of_machines()),
         };
-        FastGreedySolver::choose_initial_tasks(&mut solution, instance, &mut asigned_tasks);
+        self.seeding
+            .seed(instance, &mut solution, &mut asigned_tasks);
         while asigned_tasks.len() < instance.task_times().len() {
             FastGreedySolver::add_task(&mut solution, instance, &mut asigned_tasks);
         }
@@ -24,41 +28,23 @@ impl ProblemSolver for FastGreedySolver {
 impl FastGreedySolver {
     /// Creates a new solver
     pub fn new() -> Self {
-        FastGreedySolver {}
+        FastGreedySolver::with_seeding(Seeding::CheapestFirst)
     }
 
-    fn choose_initial_tasks(
-        solution: &mut ProblemSolution,
-        instance: &ProblemInstance,
-        asigned_tasks: &mut HashSet<usize>,
-    ) {
-        for _ in 0..instance.number_of_machines() {
-            let task = instance.setup_times()[0]
-                .iter()
-                .skip(1) // Skip the starting setup times column
-                .enumerate()
-                .filter(|(index, _)| !asigned_tasks.contains(index))
-                .map(|(index, setup)| (index, setup + instance.task_times()[index]))
-                .min_by_key(|(_, cost)| *cost);
-            let (task, tct_increment) = match task {
-                Some(values) => values,
-                None => break,
-            };
-            solution.task_assignment_matrix.push(vec![task]);
-            solution.tcts_by_machine.push(tct_increment);
-            asigned_tasks.insert(task);
-        }
+    /// Creates a new solver that chooses the first task of each machine with the seeding
+    pub fn with_seeding(seeding: Seeding) -> Self {
+        FastGreedySolver { seeding }
     }
 
-    fn add_task(
+    /// Adds the task to the end of the machine with the lowest TCT increment among the
+    /// tasks that haven't been assigned yet
+    pub(super) fn add_task(
         solution: &mut ProblemSolution,
         instance: &ProblemInstance,
         asigned_tasks: &mut HashSet<usize>,
     ) {
         let new_task = FastGreedySolver::get_best_new_task(solution, instance, asigned_tasks);
-        solution.task_assignment_matrix[new_task.machine].push(new_task.task);
-        asigned_tasks.insert(new_task.task);
-        solution.tcts_by_machine[new_task.machine] += new_task.tct_increment;
+        GreedySolver::insert_task(solution, asigned_tasks, &new_task);
     }
 
     fn get_best_new_task(
@@ -87,13 +73,13 @@ impl FastGreedySolver {
     ) -> NewTask {
         let mut task_list = solution.task_assignment_matrix[machine].clone();
         task_list.push(task);
-        let tct_increment = instance.calculate_total_completion_time(&task_list)
-            - solution.tcts_by_machine[machine];
+        let tct_increment = instance.calculate_total_completion_time(&task_list) as isize
+            - solution.tcts_by_machine[machine] as isize;
         NewTask {
             task,
             machine,
             tct_increment,
-            position: 0,
+            position: task_list.len() - 1,
         }
     }
 }
@@ -0,0 +1,73 @@
+use super::{ProblemInstance, ProblemSolution};
+
+/// Encodes a solution as a permutation of the tasks with delimiters between the machines.
+/// The delimiters are the numbers from the number of tasks onwards, so the chromosome is a
+/// permutation of the numbers from 0 to the number of tasks plus the number of machines - 1
+pub(super) fn encode(solution: &ProblemSolution) -> Vec<usize> {
+    let number_of_tasks: usize = solution.task_assignment_matrix.iter().map(Vec::len).sum();
+    let mut genes = Vec::with_capacity(number_of_tasks + solution.task_assignment_matrix.len());
+    for (machine, tasks) in solution.task_assignment_matrix.iter().enumerate() {
+        if machine > 0 {
+            genes.push(number_of_tasks + machine - 1);
+        }
+        genes.extend(tasks);
+    }
+    genes
+}
+
+/// Decodes a chromosome, assigning to each machine the tasks between two delimiters
+pub(super) fn decode(instance: &ProblemInstance, genes: &[usize]) -> ProblemSolution {
+    let number_of_tasks = instance.task_times().len();
+    let mut task_assignment_matrix = vec![Vec::new()];
+    for &gene in genes {
+        if gene >= number_of_tasks {
+            task_assignment_matrix.push(Vec::new());
+        } else {
+            task_assignment_matrix.last_mut().unwrap().push(gene);
+        }
+    }
+    ProblemSolution::new_unchecked(instance, task_assignment_matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_uses_a_delimiter_between_machines() {
+        let instance = ProblemInstance::from_file("problem_instances/I40j_4m_S1_1.txt").unwrap();
+        let mut task_assignment_matrix = vec![Vec::new(); 4];
+        for task in 0..40 {
+            task_assignment_matrix[task % 3].push(task);
+        }
+        let genes = encode(&ProblemSolution::new(&instance, task_assignment_matrix).unwrap());
+        let mut sorted_genes = genes.clone();
+        sorted_genes.sort_unstable();
+        assert_eq!(sorted_genes, (0..43).collect::<Vec<_>>());
+        assert_eq!(genes.last(), Some(&42));
+    }
+
+    #[test]
+    fn decode_reverts_encode() {
+        let instance = ProblemInstance::from_file("problem_instances/I40j_4m_S1_1.txt").unwrap();
+        let solution = ProblemSolution::new(
+            &instance,
+            vec![
+                Vec::new(),
+                (0..15).rev().collect(),
+                Vec::new(),
+                (15..40).collect(),
+            ],
+        )
+        .unwrap();
+        let decoded = decode(&instance, &encode(&solution));
+        assert_eq!(
+            decoded.get_tasks_by_machine(),
+            solution.get_tasks_by_machine()
+        );
+        assert_eq!(
+            decoded.get_tcts_by_machine(),
+            solution.get_tcts_by_machine()
+        );
+    }
+}
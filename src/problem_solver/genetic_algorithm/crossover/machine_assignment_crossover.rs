@@ -0,0 +1,42 @@
+use super::*;
+
+/// A crossover that works on the assignment of tasks to machines instead of on the
+/// chromosome. Each task goes to the machine it has in one of the parents, chosen at
+/// random, and the tasks of each machine keep the relative order they have in the first
+/// parent
+#[derive(Default)]
+pub struct MachineAssignmentCrossover {}
+
+impl Crossover for MachineAssignmentCrossover {
+    fn cross(
+        &self,
+        instance: &ProblemInstance,
+        first: &ProblemSolution,
+        second: &ProblemSolution,
+    ) -> ProblemSolution {
+        let mut machines = vec![0; instance.task_times().len()];
+        for (machine, tasks) in first.task_assignment_matrix.iter().enumerate() {
+            for &task in tasks {
+                machines[task] = machine;
+            }
+        }
+        for (machine, tasks) in second.task_assignment_matrix.iter().enumerate() {
+            for &task in tasks {
+                if rand::random::<bool>() {
+                    machines[task] = machine;
+                }
+            }
+        }
+        let mut task_assignment_matrix = vec![Vec::new(); first.task_assignment_matrix.len()];
+        for &task in first.task_assignment_matrix.iter().flatten() {
+            task_assignment_matrix[machines[task]].push(task);
+        }
+        ProblemSolution::new_unchecked(instance, task_assignment_matrix)
+    }
+}
+
+impl MachineAssignmentCrossover {
+    pub fn new() -> Self {
+        MachineAssignmentCrossover {}
+    }
+}
@@ -0,0 +1,87 @@
+use super::{chromosome, ProblemInstance, ProblemSolution};
+
+mod machine_assignment_crossover;
+mod order_crossover;
+mod partially_mapped_crossover;
+pub use machine_assignment_crossover::MachineAssignmentCrossover;
+pub use order_crossover::OrderCrossover;
+pub use partially_mapped_crossover::PartiallyMappedCrossover;
+
+/// A trait that specifies how a crossover operator should behave. A crossover operator
+/// combines two parent solutions into a new one that inherits part of the structure of
+/// each of them. The result must always be a valid solution
+pub trait Crossover {
+    /// Returns a new solution built from the two parents
+    fn cross(
+        &self,
+        instance: &ProblemInstance,
+        first: &ProblemSolution,
+        second: &ProblemSolution,
+    ) -> ProblemSolution;
+}
+
+/// Returns two random cut points of a chromosome of the specified length, such that
+/// the first one is lower or equal than the second one
+fn cut_points(length: usize) -> (usize, usize) {
+    let first = rand::random::<usize>() % (length + 1);
+    let second = rand::random::<usize>() % (length + 1);
+    (first.min(second), first.max(second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parents(instance: &ProblemInstance) -> (ProblemSolution, ProblemSolution) {
+        let mut first = vec![Vec::new(); 4];
+        for task in 0..40 {
+            first[task % 4].push(task);
+        }
+        let second = vec![
+            (0..10).rev().collect(),
+            Vec::new(),
+            (10..32).collect(),
+            (32..40).rev().collect(),
+        ];
+        (
+            ProblemSolution::new(instance, first).unwrap(),
+            ProblemSolution::new(instance, second).unwrap(),
+        )
+    }
+
+    fn assert_valid_children<C: Crossover>(crossover: C) {
+        let instance = ProblemInstance::from_file("problem_instances/I40j_4m_S1_1.txt").unwrap();
+        let (first, second) = parents(&instance);
+        for _ in 0..100 {
+            for child in &[
+                crossover.cross(&instance, &first, &second),
+                crossover.cross(&instance, &second, &first),
+            ] {
+                assert_eq!(child.get_tasks_by_machine().len(), 4);
+                let mut tasks: Vec<usize> = child
+                    .get_tasks_by_machine()
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .collect();
+                tasks.sort_unstable();
+                assert_eq!(tasks, (0..40).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn order_crossover_keeps_every_task_once() {
+        assert_valid_children(OrderCrossover::new());
+    }
+
+    #[test]
+    fn partially_mapped_crossover_keeps_every_task_once() {
+        assert_valid_children(PartiallyMappedCrossover::new());
+    }
+
+    #[test]
+    fn machine_assignment_crossover_keeps_every_task_once() {
+        assert_valid_children(MachineAssignmentCrossover::new());
+    }
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+/// The order crossover (OX). The child copies a random segment of the chromosome of the
+/// first parent and fills the rest of the positions, starting after the segment, with the
+/// remaining genes in the order in which they appear in the second parent. It keeps the
+/// relative order of the tasks, so it tends to preserve the setups of both parents
+#[derive(Default)]
+pub struct OrderCrossover {}
+
+impl Crossover for OrderCrossover {
+    fn cross(
+        &self,
+        instance: &ProblemInstance,
+        first: &ProblemSolution,
+        second: &ProblemSolution,
+    ) -> ProblemSolution {
+        let first = chromosome::encode(first);
+        let second = chromosome::encode(second);
+        let length = first.len();
+        let (start, end) = cut_points(length);
+        let mut copied = vec![false; length];
+        for &gene in &first[start..end] {
+            copied[gene] = true;
+        }
+        let mut genes = vec![0; length];
+        genes[start..end].copy_from_slice(&first[start..end]);
+        let remaining = (0..length)
+            .map(|offset| second[(end + offset) % length])
+            .filter(|&gene| !copied[gene]);
+        let positions = (0..length - (end - start)).map(|offset| (end + offset) % length);
+        for (position, gene) in positions.zip(remaining) {
+            genes[position] = gene;
+        }
+        chromosome::decode(instance, &genes)
+    }
+}
+
+impl OrderCrossover {
+    pub fn new() -> Self {
+        OrderCrossover {}
+    }
+}
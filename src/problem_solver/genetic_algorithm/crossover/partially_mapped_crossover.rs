@@ -0,0 +1,47 @@
+use super::*;
+
+/// The partially mapped crossover (PMX). The child copies a random segment of the chromosome
+/// of the first parent and the rest of the genes from the same positions of the second
+/// parent. The genes of the second parent that are already in the segment are replaced
+/// following the mapping between both parents inside the segment, so it tends to keep the
+/// absolute positions of the tasks
+#[derive(Default)]
+pub struct PartiallyMappedCrossover {}
+
+impl Crossover for PartiallyMappedCrossover {
+    fn cross(
+        &self,
+        instance: &ProblemInstance,
+        first: &ProblemSolution,
+        second: &ProblemSolution,
+    ) -> ProblemSolution {
+        let first = chromosome::encode(first);
+        let second = chromosome::encode(second);
+        let length = first.len();
+        let (start, end) = cut_points(length);
+        // The position of each gene of the segment in the first parent
+        let mut segment_positions = vec![None; length];
+        for (position, &gene) in first.iter().enumerate().take(end).skip(start) {
+            segment_positions[gene] = Some(position);
+        }
+        let genes: Vec<usize> = (0..length)
+            .map(|position| {
+                if (start..end).contains(&position) {
+                    return first[position];
+                }
+                let mut gene = second[position];
+                while let Some(mapped_position) = segment_positions[gene] {
+                    gene = second[mapped_position];
+                }
+                gene
+            })
+            .collect();
+        chromosome::decode(instance, &genes)
+    }
+}
+
+impl PartiallyMappedCrossover {
+    pub fn new() -> Self {
+        PartiallyMappedCrossover {}
+    }
+}
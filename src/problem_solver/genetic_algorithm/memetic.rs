@@ -0,0 +1,46 @@
+use super::{
+    super::grasp::local_search::LocalSearch, GeneticAlgorithm, ProblemInstance, ProblemSolution,
+    ProblemSolver, StopCriterion,
+};
+
+/// A memetic algorithm implementation. It works like the
+/// [GeneticAlgorithm](super::GeneticAlgorithm), but the initial population and every child
+/// are improved with a local search before trying to enter the population. As the local
+/// search makes the population converge quickly, a child is rejected if it's too close to
+/// any member of the population, using the [distance](ProblemSolution::distance) between
+/// solutions
+pub struct Memetic<L: LocalSearch, S: StopCriterion> {
+    genetic_algorithm: GeneticAlgorithm<S>,
+    local_search: L,
+    min_distance: usize,
+}
+
+impl<L: LocalSearch, S: StopCriterion> ProblemSolver for Memetic<L, S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let local_search = &self.local_search;
+        self.genetic_algorithm.evolve(
+            instance,
+            |solution| local_search.improve(instance, solution),
+            self.min_distance,
+        )
+    }
+}
+
+impl<L: LocalSearch, S: StopCriterion> Memetic<L, S> {
+    /// Creates a new instance that evolves the population with the parameters of the genetic
+    /// algorithm. The min distance is the number of tasks that must have a different
+    /// predecessor than in any member of the population for a child to enter it, so it must
+    /// be at least 1
+    pub fn new(
+        genetic_algorithm: GeneticAlgorithm<S>,
+        local_search: L,
+        min_distance: usize,
+    ) -> Self {
+        assert!(min_distance > 0);
+        Memetic {
+            genetic_algorithm,
+            local_search,
+            min_distance,
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use super::{
+    grasp::stop_criterion::StopCriterion, gvns::shake::Shake, ProblemInstance, ProblemSolution,
+    ProblemSolver, RandomizedGreedySolver,
+};
+
+mod chromosome;
+pub mod crossover;
+mod memetic;
+use crossover::Crossover;
+pub use memetic::Memetic;
+
+/// A steady state genetic algorithm implementation. The initial population is built with a
+/// [RandomizedGreedySolver](super::RandomizedGreedySolver). In each generation it creates as
+/// many children as the size of the population: the parents are chosen by tournament, they
+/// are combined with the [crossover operator](crossover::Crossover) and the child is mutated
+/// with some probability applying one of the [shaking operators](super::gvns::shake::Shake)
+/// with strength 1. A child replaces the worst member of the population if it's better and
+/// it isn't a copy of another member. The stop criterion is checked after each generation
+/// with the best TCT before and after it
+pub struct GeneticAlgorithm<S: StopCriterion> {
+    population_size: usize,
+    constructive_solver: RandomizedGreedySolver,
+    crossover: Box<dyn Crossover>,
+    mutations: Vec<Box<dyn Shake>>,
+    mutation_probability: f64,
+    tournament_size: usize,
+    stop_criterion: S,
+}
+
+impl<S: StopCriterion> ProblemSolver for GeneticAlgorithm<S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        self.evolve(instance, |solution| solution, 1)
+    }
+}
+
+impl<S: StopCriterion> GeneticAlgorithm<S> {
+    /// Creates a new instance with the specified arguments. The size to choose from is the
+    /// k of the randomized greedy solver that builds the initial population. The population
+    /// and the tournaments must have at least 2 members, there must be at least one mutation
+    /// and the mutation probability must be between 0 and 1
+    pub fn new(
+        population_size: usize,
+        size_to_choose_from: usize,
+        crossover: Box<dyn Crossover>,
+        mutations: Vec<Box<dyn Shake>>,
+        mutation_probability: f64,
+        tournament_size: usize,
+        stop_criterion: S,
+    ) -> Self {
+        assert!(population_size > 1 && tournament_size > 1);
+        assert!(!mutations.is_empty());
+        assert!((0.0..=1.0).contains(&mutation_probability));
+        GeneticAlgorithm {
+            population_size,
+            constructive_solver: RandomizedGreedySolver::new(size_to_choose_from),
+            crossover,
+            mutations,
+            mutation_probability,
+            tournament_size,
+            stop_criterion,
+        }
+    }
+
+    /// Runs the algorithm applying the improvement to the initial population and to each
+    /// child. A child only enters the population if its distance to every member is at
+    /// least the specified one
+    fn evolve<F>(
+        &mut self,
+        instance: &ProblemInstance,
+        improve: F,
+        min_distance: usize,
+    ) -> ProblemSolution
+    where
+        F: Fn(ProblemSolution) -> ProblemSolution,
+    {
+        let mut population: Vec<ProblemSolution> = (0..self.population_size)
+            .map(|_| improve(self.constructive_solver.solve(instance)))
+            .collect();
+        loop {
+            let previous_best_tct = best_tct(&population);
+            for _ in 0..self.population_size {
+                let child = self.crossover.cross(
+                    instance,
+                    tournament(&population, self.tournament_size),
+                    tournament(&population, self.tournament_size),
+                );
+                let child = mutate(instance, child, &self.mutations, self.mutation_probability);
+                replace_worst(&mut population, improve(child), min_distance);
+            }
+            if self
+                .stop_criterion
+                .stop(previous_best_tct, best_tct(&population))
+            {
+                return best_solution(population);
+            }
+        }
+    }
+}
+
+/// Chooses the best of the specified number of random members of the population
+fn tournament(population: &[ProblemSolution], tournament_size: usize) -> &ProblemSolution {
+    (0..tournament_size)
+        .map(|_| &population[rand::random::<usize>() % population.len()])
+        .min_by_key(|solution| solution.get_total_completion_time())
+        .unwrap()
+}
+
+/// Applies one of the mutations to the solution with the specified probability
+fn mutate(
+    instance: &ProblemInstance,
+    solution: ProblemSolution,
+    mutations: &[Box<dyn Shake>],
+    mutation_probability: f64,
+) -> ProblemSolution {
+    if rand::random::<f64>() < mutation_probability {
+        mutations[rand::random::<usize>() % mutations.len()].shake(instance, solution, 1)
+    } else {
+        solution
+    }
+}
+
+/// Replaces the worst member of the population with the solution if it's better and its
+/// distance to every member is at least the specified one
+fn replace_worst(
+    population: &mut [ProblemSolution],
+    solution: ProblemSolution,
+    min_distance: usize,
+) {
+    let (worst, worst_tct) = population
+        .iter()
+        .map(ProblemSolution::get_total_completion_time)
+        .enumerate()
+        .max_by_key(|&(_, tct)| tct)
+        .unwrap();
+    if solution.get_total_completion_time() >= worst_tct
+        || population
+            .iter()
+            .any(|member| member.distance(&solution) < min_distance)
+    {
+        return;
+    }
+    population[worst] = solution;
+}
+
+fn best_tct(population: &[ProblemSolution]) -> usize {
+    population
+        .iter()
+        .map(ProblemSolution::get_total_completion_time)
+        .min()
+        .unwrap()
+}
+
+fn best_solution(population: Vec<ProblemSolution>) -> ProblemSolution {
+    population
+        .into_iter()
+        .min_by_key(ProblemSolution::get_total_completion_time)
+        .unwrap()
+}
@@ -0,0 +1,133 @@
+use super::ProblemInstance;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A candidate list precomputed from the setup times of an instance. For each task it
+/// stores the k tasks with the cheapest setup times to go to it (its predecessors) and
+/// the k tasks with the cheapest setup times to go from it (its successors). The inactive
+/// state is considered a possible predecessor too.
+///
+/// The inter machine local searches can use it to only evaluate the moves that put a task
+/// next to one of its candidates, which reduces the size of the environment a lot in big
+/// instances
+pub(super) struct CandidateList {
+    predecessors: Vec<Vec<bool>>,
+    successors: Vec<Vec<bool>>,
+}
+
+impl CandidateList {
+    /// Creates the candidate list of an instance keeping the specified number of
+    /// predecessors and successors for each task
+    fn new(instance: &ProblemInstance, size: usize) -> Self {
+        let number_of_tasks = instance.task_times().len();
+        let setup_times = instance.setup_times();
+        let predecessors = (0..number_of_tasks)
+            .map(|task| {
+                let mut candidates: Vec<usize> = (0..=number_of_tasks)
+                    .filter(|&predecessor| predecessor != task + 1)
+                    .collect();
+                candidates.sort_by_key(|&predecessor| setup_times[predecessor][task + 1]);
+                CandidateList::to_membership(candidates.into_iter().take(size), number_of_tasks + 1)
+            })
+            .collect();
+        let successors = (0..number_of_tasks)
+            .map(|task| {
+                let mut candidates: Vec<usize> = (0..number_of_tasks)
+                    .filter(|&successor| successor != task)
+                    .collect();
+                candidates.sort_by_key(|&successor| setup_times[task + 1][successor + 1]);
+                CandidateList::to_membership(candidates.into_iter().take(size), number_of_tasks)
+            })
+            .collect();
+        CandidateList {
+            predecessors,
+            successors,
+        }
+    }
+
+    /// Checks if inserting the task in the specified position of the task list puts it
+    /// next to one of its candidates
+    pub(super) fn allows_insertion(
+        &self,
+        task: usize,
+        task_list: &[usize],
+        position: usize,
+    ) -> bool {
+        let predecessor = if position == 0 {
+            None
+        } else {
+            Some(task_list[position - 1])
+        };
+        self.allows(task, predecessor, task_list.get(position).copied())
+    }
+
+    /// Checks if putting the task in place of the one in the specified position of the task
+    /// list puts it next to one of its candidates
+    pub(super) fn allows_replacement(
+        &self,
+        task: usize,
+        task_list: &[usize],
+        position: usize,
+    ) -> bool {
+        let predecessor = if position == 0 {
+            None
+        } else {
+            Some(task_list[position - 1])
+        };
+        self.allows(task, predecessor, task_list.get(position + 1).copied())
+    }
+
+    fn allows(&self, task: usize, predecessor: Option<usize>, successor: Option<usize>) -> bool {
+        // The inactive state is the first row of the setup times matrix
+        let predecessor = predecessor.map_or(0, |predecessor| predecessor + 1);
+        self.predecessors[task][predecessor]
+            || successor.is_some_and(|successor| self.successors[task][successor])
+    }
+
+    fn to_membership(candidates: impl Iterator<Item = usize>, length: usize) -> Vec<bool> {
+        let mut membership = vec![false; length];
+        for candidate in candidates {
+            membership[candidate] = true;
+        }
+        membership
+    }
+}
+
+/// The [candidate list](CandidateList) of a local search. The local searches only know the
+/// number of candidates, so the list is built the first time it's needed and built again
+/// when the search is used with an instance with different setup times. The setup times of
+/// the last instance are kept to detect it
+pub(super) struct CandidateListCache {
+    size: usize,
+    candidate_list: RefCell<Option<CachedList>>,
+}
+
+/// The setup times from which a candidate list was built and the list
+type CachedList = (Vec<Vec<usize>>, Rc<CandidateList>);
+
+impl CandidateListCache {
+    /// Creates an empty cache for lists with the specified number of candidates per task.
+    /// The size must be greater than 0
+    pub(super) fn new(size: usize) -> Self {
+        assert!(size > 0);
+        CandidateListCache {
+            size,
+            candidate_list: RefCell::new(None),
+        }
+    }
+
+    /// Returns the candidate list of the instance
+    pub(super) fn get(&self, instance: &ProblemInstance) -> Rc<CandidateList> {
+        let mut candidate_list = self.candidate_list.borrow_mut();
+        match candidate_list.as_ref() {
+            Some((setup_times, cached)) if setup_times == instance.setup_times() => {
+                Rc::clone(cached)
+            }
+            _ => {
+                let new_list = Rc::new(CandidateList::new(instance, self.size));
+                *candidate_list = Some((instance.setup_times().clone(), Rc::clone(&new_list)));
+                new_list
+            }
+        }
+    }
+}
@@ -0,0 +1,415 @@
+use super::*;
+
+/// A local search that moves tasks along chains of different machines. A task of the
+/// machine A replaces a task of the machine B, that task replaces one of the machine C
+/// and so on. The chain can be closed in two ways:
+/// * Cyclic exchange: The last ejected task replaces the first one in the machine A
+/// * Path exchange: The first task leaves the machine A and the last ejected task is
+///   inserted in the best position of a machine that isn't part of the chain
+///
+/// The chains are found using an improvement graph, in which each task is a node and
+/// an arc from a task to another one of a different machine costs the TCT increment of
+/// that replacement. The search for negative subset-disjoint cycles is done with a label
+/// correcting heuristic that only keeps the best path to each node in each step, so it
+/// stays tractable. The maximum number of machines in a chain can be specified in the
+/// constructor
+pub struct CyclicExchange {
+    max_chain_length: usize,
+    candidate_list: Option<CandidateListCache>,
+}
+
+/// The position of a task in the solution
+#[derive(Clone, Copy, PartialEq)]
+struct Node {
+    machine: usize,
+    index: usize,
+    task: usize,
+}
+
+/// A path in the improvement graph that starts in the root node
+#[derive(Clone)]
+struct Label {
+    cost: isize,
+    path: Vec<usize>,
+}
+
+/// An improving chain found in the improvement graph
+struct Chain {
+    cost: isize,
+    path: Vec<usize>,
+    closing_machine: Option<usize>,
+}
+
+/// The precomputed costs of the improvement graph
+struct ImprovementGraph {
+    nodes: Vec<Node>,
+    replacement_costs: Vec<Vec<Option<isize>>>,
+    removal_costs: Vec<isize>,
+    insertion_costs: Vec<Vec<Option<(isize, usize)>>>,
+}
+
+impl LocalSearch for CyclicExchange {
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let number_of_tasks = instance.task_times().len();
+        self.perform_search_with_bits(instance, solution, &mut DontLookBits::new(number_of_tasks))
+    }
+
+    fn perform_search_with_bits(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        dont_look_bits: &mut DontLookBits,
+    ) -> ProblemSolution {
+        let graph = self.build_graph(instance, &solution);
+        let mut best_chain: Option<Chain> = None;
+        for root in 0..graph.nodes.len() {
+            if dont_look_bits.is_set(graph.nodes[root].task) {
+                continue;
+            }
+            match self.find_chain(&graph, root) {
+                Some(chain)
+                    if best_chain
+                        .as_ref()
+                        .is_none_or(|best| chain.cost < best.cost) =>
+                {
+                    best_chain = Some(chain)
+                }
+                Some(_) => {}
+                None => dont_look_bits.set(graph.nodes[root].task),
+            }
+        }
+        match best_chain {
+            Some(chain) => CyclicExchange::get_solution(instance, &solution, &graph, &chain),
+            None => solution,
+        }
+    }
+}
+
+impl CyclicExchange {
+    /// Creates a new instance that will build chains of at most the specified number
+    /// of machines. It must be at least 2
+    pub fn new(max_chain_length: usize) -> Self {
+        assert!(max_chain_length > 1);
+        CyclicExchange {
+            max_chain_length,
+            candidate_list: None,
+        }
+    }
+
+    /// Creates an instance that only adds to the improvement graph the arcs and
+    /// insertions that put the task next to one of its candidates, keeping the specified
+    /// number of candidates for each task
+    pub fn with_candidate_list(max_chain_length: usize, candidate_list_size: usize) -> Self {
+        assert!(max_chain_length > 1);
+        CyclicExchange {
+            max_chain_length,
+            candidate_list: Some(CandidateListCache::new(candidate_list_size)),
+        }
+    }
+
+    fn build_graph(
+        &self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+    ) -> ImprovementGraph {
+        let candidate_list = self
+            .candidate_list
+            .as_ref()
+            .map(|cache| cache.get(instance));
+        let nodes: Vec<Node> = solution
+            .task_assignment_matrix
+            .iter()
+            .enumerate()
+            .flat_map(|(machine, tasks)| {
+                tasks.iter().enumerate().map(move |(index, &task)| Node {
+                    machine,
+                    index,
+                    task,
+                })
+            })
+            .collect();
+        let replacement_costs = nodes
+            .iter()
+            .map(|from| {
+                nodes
+                    .iter()
+                    .map(|to| {
+                        let task_list = &solution.task_assignment_matrix[to.machine];
+                        if from.machine == to.machine
+                            || candidate_list.as_ref().is_some_and(|candidate_list| {
+                                !candidate_list.allows_replacement(from.task, task_list, to.index)
+                            })
+                        {
+                            return None;
+                        }
+                        let mut task_list = task_list.clone();
+                        task_list[to.index] = from.task;
+                        Some(
+                            instance.calculate_total_completion_time(&task_list) as isize
+                                - solution.tcts_by_machine[to.machine] as isize,
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+        let removal_costs = nodes
+            .iter()
+            .map(|node| {
+                let mut task_list = solution.task_assignment_matrix[node.machine].clone();
+                task_list.remove(node.index);
+                instance.calculate_total_completion_time(&task_list) as isize
+                    - solution.tcts_by_machine[node.machine] as isize
+            })
+            .collect();
+        let insertion_costs = nodes
+            .iter()
+            .map(|node| {
+                (0..solution.task_assignment_matrix.len())
+                    .map(|machine| {
+                        (0..=solution.task_assignment_matrix[machine].len())
+                            .filter(|&position| {
+                                candidate_list.as_ref().is_none_or(|candidate_list| {
+                                    candidate_list.allows_insertion(
+                                        node.task,
+                                        &solution.task_assignment_matrix[machine],
+                                        position,
+                                    )
+                                })
+                            })
+                            .map(|position| {
+                                let mut task_list =
+                                    solution.task_assignment_matrix[machine].clone();
+                                task_list.insert(position, node.task);
+                                let cost = instance.calculate_total_completion_time(&task_list)
+                                    as isize
+                                    - solution.tcts_by_machine[machine] as isize;
+                                (cost, position)
+                            })
+                            .min()
+                    })
+                    .collect()
+            })
+            .collect();
+        ImprovementGraph {
+            nodes,
+            replacement_costs,
+            removal_costs,
+            insertion_costs,
+        }
+    }
+
+    fn find_chain(&self, graph: &ImprovementGraph, root: usize) -> Option<Chain> {
+        let mut best_chain: Option<Chain> = None;
+        let mut labels = vec![None; graph.nodes.len()];
+        labels[root] = Some(Label {
+            cost: 0,
+            path: vec![root],
+        });
+        for _ in 1..self.max_chain_length {
+            let mut new_labels: Vec<Option<Label>> = vec![None; graph.nodes.len()];
+            for label in labels.iter().flatten() {
+                let last = *label.path.last().unwrap();
+                for (next, arc_cost) in graph.replacement_costs[last].iter().enumerate() {
+                    let cost = match arc_cost {
+                        Some(arc_cost) => label.cost + arc_cost,
+                        None => continue,
+                    };
+                    let machine = graph.nodes[next].machine;
+                    if cost >= 0
+                        || label
+                            .path
+                            .iter()
+                            .any(|&node| graph.nodes[node].machine == machine)
+                    {
+                        continue;
+                    }
+                    if let Some(other) = &new_labels[next] {
+                        if other.cost <= cost {
+                            continue;
+                        }
+                    }
+                    let mut path = label.path.clone();
+                    path.push(next);
+                    new_labels[next] = Some(Label { cost, path });
+                }
+            }
+            for chain in new_labels
+                .iter()
+                .flatten()
+                .filter_map(|label| CyclicExchange::close_chain(graph, label))
+            {
+                if chain.cost < best_chain.as_ref().map_or(0, |best| best.cost) {
+                    best_chain = Some(chain);
+                }
+            }
+            labels = new_labels;
+        }
+        best_chain
+    }
+
+    fn close_chain(graph: &ImprovementGraph, label: &Label) -> Option<Chain> {
+        let root = label.path[0];
+        let last = *label.path.last().unwrap();
+        let cyclic_exchange =
+            graph.replacement_costs[last][root].map(|arc_cost| (label.cost + arc_cost, None));
+        let path_exchanges = (0..graph.insertion_costs[last].len())
+            .filter(|&machine| {
+                !label
+                    .path
+                    .iter()
+                    .any(|&node| graph.nodes[node].machine == machine)
+            })
+            .filter_map(|machine| {
+                graph.insertion_costs[last][machine].map(|(insertion_cost, _)| {
+                    (
+                        label.cost + graph.removal_costs[root] + insertion_cost,
+                        Some(machine),
+                    )
+                })
+            });
+        cyclic_exchange
+            .into_iter()
+            .chain(path_exchanges)
+            .min()
+            .map(|(cost, closing_machine)| Chain {
+                cost,
+                path: label.path.clone(),
+                closing_machine,
+            })
+    }
+
+    fn get_solution(
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+        graph: &ImprovementGraph,
+        chain: &Chain,
+    ) -> ProblemSolution {
+        let mut possible_solution = solution.clone();
+        for (&from, &to) in chain.path.iter().zip(chain.path.iter().skip(1)) {
+            let to = graph.nodes[to];
+            possible_solution.task_assignment_matrix[to.machine][to.index] = graph.nodes[from].task;
+        }
+        let root = graph.nodes[chain.path[0]];
+        let last = *chain.path.last().unwrap();
+        match chain.closing_machine {
+            Some(machine) => {
+                possible_solution.task_assignment_matrix[root.machine].remove(root.index);
+                let (_, position) = graph.insertion_costs[last][machine].unwrap();
+                possible_solution.task_assignment_matrix[machine]
+                    .insert(position, graph.nodes[last].task);
+                possible_solution.tcts_by_machine[machine] = instance
+                    .calculate_total_completion_time(
+                        &possible_solution.task_assignment_matrix[machine],
+                    );
+            }
+            None => {
+                possible_solution.task_assignment_matrix[root.machine][root.index] =
+                    graph.nodes[last].task
+            }
+        }
+        for node in chain.path.iter().map(|&node| graph.nodes[node]) {
+            possible_solution.tcts_by_machine[node.machine] = instance
+                .calculate_total_completion_time(
+                    &possible_solution.task_assignment_matrix[node.machine],
+                );
+        }
+        possible_solution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solution(instance: &ProblemInstance) -> ProblemSolution {
+        let mut task_assignment_matrix = vec![Vec::new(); 4];
+        for task in 0..40 {
+            task_assignment_matrix[task % 4].push(task);
+        }
+        ProblemSolution::new(instance, task_assignment_matrix).unwrap()
+    }
+
+    fn assert_chain_applies(
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+        graph: &ImprovementGraph,
+        chain: &Chain,
+    ) {
+        let new_solution = CyclicExchange::get_solution(instance, solution, graph, chain);
+        assert_eq!(
+            new_solution.get_total_completion_time() as isize,
+            solution.get_total_completion_time() as isize + chain.cost
+        );
+        let mut tasks: Vec<usize> = new_solution
+            .get_tasks_by_machine()
+            .iter()
+            .flatten()
+            .copied()
+            .collect();
+        tasks.sort_unstable();
+        assert_eq!(tasks, (0..40).collect::<Vec<_>>());
+        for (tasks, &tct) in new_solution
+            .get_tasks_by_machine()
+            .iter()
+            .zip(new_solution.get_tcts_by_machine())
+        {
+            assert_eq!(instance.calculate_total_completion_time(tasks), tct);
+        }
+    }
+
+    #[test]
+    fn cyclic_chain_cost_matches_solution() {
+        let instance = ProblemInstance::from_file("problem_instances/I40j_4m_S1_1.txt").unwrap();
+        let solution = solution(&instance);
+        let graph = CyclicExchange::new(3).build_graph(&instance, &solution);
+        // Each machine has 10 nodes, so the chain goes through the machines 0, 1 and 2
+        let path = vec![0, 11, 22];
+        let cost = graph.replacement_costs[0][11].unwrap()
+            + graph.replacement_costs[11][22].unwrap()
+            + graph.replacement_costs[22][0].unwrap();
+        let chain = Chain {
+            cost,
+            path,
+            closing_machine: None,
+        };
+        assert_chain_applies(&instance, &solution, &graph, &chain);
+    }
+
+    #[test]
+    fn path_chain_cost_matches_solution() {
+        let instance = ProblemInstance::from_file("problem_instances/I40j_4m_S1_1.txt").unwrap();
+        let solution = solution(&instance);
+        let graph = CyclicExchange::new(3).build_graph(&instance, &solution);
+        // The task of the machine 1 is inserted in the machine 2
+        let path = vec![3, 14];
+        let cost = graph.replacement_costs[3][14].unwrap()
+            + graph.removal_costs[3]
+            + graph.insertion_costs[14][2].unwrap().0;
+        let chain = Chain {
+            cost,
+            path,
+            closing_machine: Some(2),
+        };
+        assert_chain_applies(&instance, &solution, &graph, &chain);
+    }
+
+    #[test]
+    fn found_chains_cost_matches_solution() {
+        let instance = ProblemInstance::from_file("problem_instances/I40j_4m_S1_1.txt").unwrap();
+        let solution = solution(&instance);
+        let search = CyclicExchange::new(4);
+        let graph = search.build_graph(&instance, &solution);
+        let chains: Vec<Chain> = (0..graph.nodes.len())
+            .filter_map(|root| search.find_chain(&graph, root))
+            .collect();
+        assert!(!chains.is_empty());
+        for chain in &chains {
+            assert!(chain.cost < 0);
+            assert_chain_applies(&instance, &solution, &graph, chain);
+        }
+    }
+}
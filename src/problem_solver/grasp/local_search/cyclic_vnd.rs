@@ -0,0 +1,48 @@
+use super::*;
+
+/// A cyclic variable neighbourhood descent. Unlike [Vnd](Vnd), when an environment
+/// improves the solution it doesn't go back to the first one, it continues with the
+/// next one. It stops when all the environments in a row fail to improve the solution
+pub struct CyclicVnd {
+    searches: Vec<Box<dyn LocalSearch>>,
+}
+
+impl LocalSearch for CyclicVnd {
+    fn improve(&self, instance: &ProblemInstance, solution: ProblemSolution) -> ProblemSolution {
+        self.perform_search(instance, solution)
+    }
+
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let mut dont_look_bits =
+            DontLookBits::per_search(instance.task_times().len(), self.searches.len());
+        let mut search_index = 0;
+        let mut searches_without_improvement = 0;
+        while searches_without_improvement < self.searches.len() {
+            let new_solution = self.searches[search_index].perform_search_with_bits(
+                instance,
+                solution.clone(),
+                &mut dont_look_bits[search_index],
+            );
+            if solution.get_total_completion_time() <= new_solution.get_total_completion_time() {
+                searches_without_improvement += 1;
+            } else {
+                DontLookBits::reset_all_changed(&mut dont_look_bits, &solution, &new_solution);
+                solution = new_solution;
+                searches_without_improvement = 0;
+            }
+            search_index = (search_index + 1) % self.searches.len();
+        }
+        solution
+    }
+}
+
+impl CyclicVnd {
+    /// Creates a new instance with the specified environments
+    pub fn new(searches: Vec<Box<dyn LocalSearch>>) -> Self {
+        CyclicVnd { searches }
+    }
+}
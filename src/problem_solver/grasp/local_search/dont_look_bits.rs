@@ -0,0 +1,68 @@
+use super::ProblemSolution;
+
+/// The don't look bits of the tasks of an instance. A set bit means that the moves of
+/// that task didn't improve the solution the last time they were evaluated, so the local
+/// searches can skip them until a move changes the machine in which the task is
+#[derive(Clone)]
+pub struct DontLookBits {
+    bits: Vec<bool>,
+}
+
+impl DontLookBits {
+    /// Creates the bits for the specified number of tasks. All of them start reset
+    pub fn new(number_of_tasks: usize) -> Self {
+        DontLookBits {
+            bits: vec![false; number_of_tasks],
+        }
+    }
+
+    /// Creates the bits of each environment of a descent over several of them. Each
+    /// environment has its own bits, a task that can't be improved in one of them might be
+    /// improved in another one
+    pub fn per_search(number_of_tasks: usize, number_of_searches: usize) -> Vec<Self> {
+        vec![DontLookBits::new(number_of_tasks); number_of_searches]
+    }
+
+    /// Resets in the bits of every environment the tasks of the machines touched by the
+    /// move from the old solution to the new one
+    pub fn reset_all_changed(
+        dont_look_bits: &mut [DontLookBits],
+        old: &ProblemSolution,
+        new: &ProblemSolution,
+    ) {
+        for bits in dont_look_bits.iter_mut() {
+            bits.reset_changed_machines(old, new);
+        }
+    }
+
+    /// Checks if the moves of the task should be skipped
+    pub fn is_set(&self, task: usize) -> bool {
+        self.bits[task]
+    }
+
+    /// Marks the task so its moves are skipped
+    pub fn set(&mut self, task: usize) {
+        self.bits[task] = true;
+    }
+
+    /// Resets the bits of all the tasks
+    pub fn reset_all(&mut self) {
+        self.bits.iter_mut().for_each(|bit| *bit = false);
+    }
+
+    /// Resets the bits of the tasks that are in the machines that are different between
+    /// the old solution and the new one, that is, the machines touched by the last move
+    pub fn reset_changed_machines(&mut self, old: &ProblemSolution, new: &ProblemSolution) {
+        for (old_tasks, new_tasks) in old
+            .task_assignment_matrix
+            .iter()
+            .zip(new.task_assignment_matrix.iter())
+        {
+            if old_tasks != new_tasks {
+                for &task in new_tasks {
+                    self.bits[task] = false;
+                }
+            }
+        }
+    }
+}
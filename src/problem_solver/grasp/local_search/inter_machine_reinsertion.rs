@@ -1,7 +1,9 @@
 use super::*;
 
 /// A local search that consists on reinserting a task in a different machine
-pub struct InterMachineReinsertion {}
+pub struct InterMachineReinsertion {
+    candidate_list: Option<CandidateListCache>,
+}
 
 impl LocalSearch for InterMachineReinsertion {
     fn perform_search(
@@ -9,37 +11,122 @@ impl LocalSearch for InterMachineReinsertion {
         instance: &ProblemInstance,
         solution: ProblemSolution,
     ) -> ProblemSolution {
+        let number_of_tasks = instance.task_times().len();
+        self.perform_search_with_bits(instance, solution, &mut DontLookBits::new(number_of_tasks))
+    }
+
+    fn perform_search_with_bits(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        dont_look_bits: &mut DontLookBits,
+    ) -> ProblemSolution {
+        let candidate_list = self
+            .candidate_list
+            .as_ref()
+            .map(|cache| cache.get(instance));
         let solution_ref = &solution;
-        (0..solution.task_assignment_matrix.len())
-            .filter(|&machine| solution.task_assignment_matrix[machine].len() > 0)
-            .flat_map(|from_machine| {
-                (0..solution.task_assignment_matrix[from_machine].len()).flat_map(
-                    move |task_index| {
-                        (0..solution_ref.task_assignment_matrix.len()).flat_map(move |to_machine| {
-                            (0..=solution_ref.task_assignment_matrix[to_machine].len())
-                                .filter(move |&to_machine| to_machine != from_machine)
-                                .map(move |possible_insertion_index| {
-                                    InterMachineReinsertion::get_solution(
-                                        instance,
-                                        solution_ref,
-                                        from_machine,
-                                        task_index,
-                                        to_machine,
-                                        possible_insertion_index,
-                                    )
-                                })
-                        })
-                    },
-                )
-            })
-            .min_by_key(|solution| solution.get_total_completion_time())
-            .unwrap_or(solution)
+        best_move_by_task(solution_ref, dont_look_bits, |from_machine, task_index| {
+            self.moves(
+                instance,
+                solution_ref,
+                candidate_list.as_deref(),
+                from_machine,
+                task_index,
+            )
+        })
+        .unwrap_or(solution)
+    }
+}
+
+impl Neighbourhood for InterMachineReinsertion {
+    fn neighbours(
+        &self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+    ) -> Vec<ProblemSolution> {
+        let candidate_list = self
+            .candidate_list
+            .as_ref()
+            .map(|cache| cache.get(instance));
+        all_moves(solution, |from_machine, task_index| {
+            self.moves(
+                instance,
+                solution,
+                candidate_list.as_deref(),
+                from_machine,
+                task_index,
+            )
+        })
     }
 }
 
 impl InterMachineReinsertion {
     pub fn new() -> Self {
-        InterMachineReinsertion {}
+        InterMachineReinsertion {
+            candidate_list: None,
+        }
+    }
+
+    /// Creates an instance that only evaluates the insertions that put the task next
+    /// to one of its candidates, keeping the specified number of candidates for each task
+    pub fn with_candidate_list(size: usize) -> Self {
+        InterMachineReinsertion {
+            candidate_list: Some(CandidateListCache::new(size)),
+        }
+    }
+
+    fn moves<'a>(
+        &'a self,
+        instance: &'a ProblemInstance,
+        solution: &'a ProblemSolution,
+        candidate_list: Option<&'a CandidateList>,
+        from_machine: usize,
+        task_index: usize,
+    ) -> impl Iterator<Item = ProblemSolution> + 'a {
+        (0..solution.task_assignment_matrix.len())
+            .filter(move |&to_machine| to_machine != from_machine)
+            .flat_map(move |to_machine| {
+                (0..=solution.task_assignment_matrix[to_machine].len())
+                    .filter(move |&possible_insertion_index| {
+                        InterMachineReinsertion::is_candidate(
+                            candidate_list,
+                            solution,
+                            from_machine,
+                            task_index,
+                            to_machine,
+                            possible_insertion_index,
+                        )
+                    })
+                    .map(move |possible_insertion_index| {
+                        InterMachineReinsertion::get_solution(
+                            instance,
+                            solution,
+                            from_machine,
+                            task_index,
+                            to_machine,
+                            possible_insertion_index,
+                        )
+                    })
+            })
+    }
+
+    fn is_candidate(
+        candidate_list: Option<&CandidateList>,
+        solution: &ProblemSolution,
+        from_machine: usize,
+        task_index: usize,
+        to_machine: usize,
+        possible_insertion_index: usize,
+    ) -> bool {
+        match candidate_list {
+            Some(candidate_list) => candidate_list.allows_insertion(
+                solution.task_assignment_matrix[from_machine][task_index],
+                &solution.task_assignment_matrix[to_machine],
+                possible_insertion_index,
+            ),
+            None => true,
+        }
     }
 
     fn get_solution(
@@ -51,8 +138,8 @@ impl InterMachineReinsertion {
         possible_insertion_index: usize,
     ) -> ProblemSolution {
         let mut possible_solution = solution.clone();
-        possible_solution.task_assignment_matrix[to_machine]
-            .insert(possible_insertion_index, task_index);
+        let task = possible_solution.task_assignment_matrix[from_machine].remove(task_index);
+        possible_solution.task_assignment_matrix[to_machine].insert(possible_insertion_index, task);
         possible_solution.tcts_by_machine[from_machine] = instance.calculate_total_completion_time(
             &possible_solution.task_assignment_matrix[from_machine],
         );
@@ -1,7 +1,9 @@
 use super::*;
 
 /// An anxious local search that consists on reinserting a task in a different machine
-pub struct InterMachineReinsertionAnxious {}
+pub struct InterMachineReinsertionAnxious {
+    candidate_list: Option<CandidateListCache>,
+}
 
 impl LocalSearch for InterMachineReinsertionAnxious {
     fn perform_search(
@@ -9,12 +11,30 @@ impl LocalSearch for InterMachineReinsertionAnxious {
         instance: &ProblemInstance,
         solution: ProblemSolution,
     ) -> ProblemSolution {
+        let number_of_tasks = instance.task_times().len();
+        self.perform_search_with_bits(instance, solution, &mut DontLookBits::new(number_of_tasks))
+    }
+
+    fn perform_search_with_bits(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        dont_look_bits: &mut DontLookBits,
+    ) -> ProblemSolution {
+        let candidate_list = self
+            .candidate_list
+            .as_ref()
+            .map(|cache| cache.get(instance));
         let solution_tct = solution.get_total_completion_time();
         for from_machine in 0..solution.task_assignment_matrix.len() {
             if solution.task_assignment_matrix[from_machine].len() == 0 {
                 continue;
             }
             for task_index in 0..solution.task_assignment_matrix[from_machine].len() {
+                let task = solution.task_assignment_matrix[from_machine][task_index];
+                if dont_look_bits.is_set(task) {
+                    continue;
+                }
                 for to_machine in 0..solution.task_assignment_matrix.len() {
                     if from_machine == to_machine {
                         continue;
@@ -22,6 +42,16 @@ impl LocalSearch for InterMachineReinsertionAnxious {
                     for possible_insertion_index in
                         0..=solution.task_assignment_matrix[to_machine].len()
                     {
+                        if !InterMachineReinsertionAnxious::is_candidate(
+                            candidate_list.as_deref(),
+                            &solution,
+                            from_machine,
+                            task_index,
+                            to_machine,
+                            possible_insertion_index,
+                        ) {
+                            continue;
+                        }
                         let new_solution = InterMachineReinsertionAnxious::get_solution(
                             instance,
                             &solution,
@@ -35,6 +65,7 @@ impl LocalSearch for InterMachineReinsertionAnxious {
                         }
                     }
                 }
+                dont_look_bits.set(task);
             }
         }
         solution
@@ -43,7 +74,35 @@ impl LocalSearch for InterMachineReinsertionAnxious {
 
 impl InterMachineReinsertionAnxious {
     pub fn new() -> Self {
-        InterMachineReinsertionAnxious {}
+        InterMachineReinsertionAnxious {
+            candidate_list: None,
+        }
+    }
+
+    /// Creates an instance that only evaluates the insertions that put the task next
+    /// to one of its candidates, keeping the specified number of candidates for each task
+    pub fn with_candidate_list(size: usize) -> Self {
+        InterMachineReinsertionAnxious {
+            candidate_list: Some(CandidateListCache::new(size)),
+        }
+    }
+
+    fn is_candidate(
+        candidate_list: Option<&CandidateList>,
+        solution: &ProblemSolution,
+        from_machine: usize,
+        task_index: usize,
+        to_machine: usize,
+        possible_insertion_index: usize,
+    ) -> bool {
+        match candidate_list {
+            Some(candidate_list) => candidate_list.allows_insertion(
+                solution.task_assignment_matrix[from_machine][task_index],
+                &solution.task_assignment_matrix[to_machine],
+                possible_insertion_index,
+            ),
+            None => true,
+        }
     }
 
     fn get_solution(
@@ -55,8 +114,8 @@ impl InterMachineReinsertionAnxious {
         possible_insertion_index: usize,
     ) -> ProblemSolution {
         let mut possible_solution = solution.clone();
-        possible_solution.task_assignment_matrix[to_machine]
-            .insert(possible_insertion_index, task_index);
+        let task = possible_solution.task_assignment_matrix[from_machine].remove(task_index);
+        possible_solution.task_assignment_matrix[to_machine].insert(possible_insertion_index, task);
         possible_solution.tcts_by_machine[from_machine] = instance.calculate_total_completion_time(
             &possible_solution.task_assignment_matrix[from_machine],
         );
@@ -2,7 +2,9 @@ use super::*;
 use std::mem;
 
 /// A local search that consists on doing swaps between tasks in the different machines
-pub struct InterMachineSwap {}
+pub struct InterMachineSwap {
+    candidate_list: Option<CandidateListCache>,
+}
 
 impl LocalSearch for InterMachineSwap {
     fn perform_search(
@@ -10,39 +12,130 @@ impl LocalSearch for InterMachineSwap {
         instance: &ProblemInstance,
         solution: ProblemSolution,
     ) -> ProblemSolution {
+        let number_of_tasks = instance.task_times().len();
+        self.perform_search_with_bits(instance, solution, &mut DontLookBits::new(number_of_tasks))
+    }
+
+    fn perform_search_with_bits(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        dont_look_bits: &mut DontLookBits,
+    ) -> ProblemSolution {
+        let candidate_list = self
+            .candidate_list
+            .as_ref()
+            .map(|cache| cache.get(instance));
         let solution_ref = &solution;
-        (0..solution.task_assignment_matrix.len())
-            .filter(|&machine| solution.task_assignment_matrix[machine].len() > 1)
-            .flat_map(|from_machine| {
-                (0..solution.task_assignment_matrix[from_machine].len()).flat_map(
-                    move |task_index| {
-                        (0..solution_ref.task_assignment_matrix.len())
-                            .filter(move |&to_machine| to_machine != from_machine)
-                            .flat_map(move |to_machine| {
-                                (0..solution_ref.task_assignment_matrix[to_machine].len()).map(
-                                    move |possible_swap_index| {
-                                        InterMachineSwap::get_solution(
-                                            instance,
-                                            solution_ref,
-                                            from_machine,
-                                            task_index,
-                                            to_machine,
-                                            possible_swap_index,
-                                        )
-                                    },
-                                )
-                            })
-                    },
-                )
-            })
-            .min_by_key(|solution| solution.get_total_completion_time())
-            .unwrap_or(solution)
+        best_move_by_task(solution_ref, dont_look_bits, |from_machine, task_index| {
+            self.moves(
+                instance,
+                solution_ref,
+                candidate_list.as_deref(),
+                from_machine,
+                task_index,
+            )
+        })
+        .unwrap_or(solution)
+    }
+}
+
+impl Neighbourhood for InterMachineSwap {
+    fn neighbours(
+        &self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+    ) -> Vec<ProblemSolution> {
+        let candidate_list = self
+            .candidate_list
+            .as_ref()
+            .map(|cache| cache.get(instance));
+        all_moves(solution, |from_machine, task_index| {
+            self.moves(
+                instance,
+                solution,
+                candidate_list.as_deref(),
+                from_machine,
+                task_index,
+            )
+        })
     }
 }
 
 impl InterMachineSwap {
     pub fn new() -> Self {
-        InterMachineSwap {}
+        InterMachineSwap {
+            candidate_list: None,
+        }
+    }
+
+    /// Creates an instance that only evaluates the swaps that put one of the tasks
+    /// next to one of its candidates, keeping the specified number of candidates for each task
+    pub fn with_candidate_list(size: usize) -> Self {
+        InterMachineSwap {
+            candidate_list: Some(CandidateListCache::new(size)),
+        }
+    }
+
+    fn moves<'a>(
+        &'a self,
+        instance: &'a ProblemInstance,
+        solution: &'a ProblemSolution,
+        candidate_list: Option<&'a CandidateList>,
+        from_machine: usize,
+        task_index: usize,
+    ) -> impl Iterator<Item = ProblemSolution> + 'a {
+        (0..solution.task_assignment_matrix.len())
+            .filter(move |&to_machine| to_machine != from_machine)
+            .flat_map(move |to_machine| {
+                (0..solution.task_assignment_matrix[to_machine].len())
+                    .filter(move |&possible_swap_index| {
+                        InterMachineSwap::is_candidate(
+                            candidate_list,
+                            solution,
+                            from_machine,
+                            task_index,
+                            to_machine,
+                            possible_swap_index,
+                        )
+                    })
+                    .map(move |possible_swap_index| {
+                        InterMachineSwap::get_solution(
+                            instance,
+                            solution,
+                            from_machine,
+                            task_index,
+                            to_machine,
+                            possible_swap_index,
+                        )
+                    })
+            })
+    }
+
+    fn is_candidate(
+        candidate_list: Option<&CandidateList>,
+        solution: &ProblemSolution,
+        from_machine: usize,
+        task_index: usize,
+        to_machine: usize,
+        possible_swap_index: usize,
+    ) -> bool {
+        let from_tasks = &solution.task_assignment_matrix[from_machine];
+        let to_tasks = &solution.task_assignment_matrix[to_machine];
+        match candidate_list {
+            Some(candidate_list) => {
+                candidate_list.allows_replacement(
+                    from_tasks[task_index],
+                    to_tasks,
+                    possible_swap_index,
+                ) || candidate_list.allows_replacement(
+                    to_tasks[possible_swap_index],
+                    from_tasks,
+                    task_index,
+                )
+            }
+            None => true,
+        }
     }
 
     fn get_solution(
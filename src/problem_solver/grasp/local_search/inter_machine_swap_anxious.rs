@@ -2,7 +2,9 @@ use super::*;
 use std::mem;
 
 /// An anxious local search that consists on doing swaps between tasks in the different machines
-pub struct InterMachineSwapAnxious {}
+pub struct InterMachineSwapAnxious {
+    candidate_list: Option<CandidateListCache>,
+}
 
 impl LocalSearch for InterMachineSwapAnxious {
     fn perform_search(
@@ -10,18 +12,46 @@ impl LocalSearch for InterMachineSwapAnxious {
         instance: &ProblemInstance,
         solution: ProblemSolution,
     ) -> ProblemSolution {
+        let number_of_tasks = instance.task_times().len();
+        self.perform_search_with_bits(instance, solution, &mut DontLookBits::new(number_of_tasks))
+    }
+
+    fn perform_search_with_bits(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        dont_look_bits: &mut DontLookBits,
+    ) -> ProblemSolution {
+        let candidate_list = self
+            .candidate_list
+            .as_ref()
+            .map(|cache| cache.get(instance));
         let solution_tct = solution.get_total_completion_time();
         for from_machine in 0..solution.task_assignment_matrix.len() {
             if solution.task_assignment_matrix[from_machine].len() == 0 {
                 continue;
             }
             for task_index in 0..solution.task_assignment_matrix[from_machine].len() {
+                let task = solution.task_assignment_matrix[from_machine][task_index];
+                if dont_look_bits.is_set(task) {
+                    continue;
+                }
                 for to_machine in 0..solution.task_assignment_matrix.len() {
                     if from_machine == to_machine {
                         continue;
                     }
                     for possible_task_index in 0..solution.task_assignment_matrix[to_machine].len()
                     {
+                        if !InterMachineSwapAnxious::is_candidate(
+                            candidate_list.as_deref(),
+                            &solution,
+                            from_machine,
+                            task_index,
+                            to_machine,
+                            possible_task_index,
+                        ) {
+                            continue;
+                        }
                         let new_solution = InterMachineSwapAnxious::get_solution(
                             instance,
                             &solution,
@@ -35,6 +65,7 @@ impl LocalSearch for InterMachineSwapAnxious {
                         }
                     }
                 }
+                dont_look_bits.set(task);
             }
         }
         solution
@@ -43,7 +74,43 @@ impl LocalSearch for InterMachineSwapAnxious {
 
 impl InterMachineSwapAnxious {
     pub fn new() -> Self {
-        InterMachineSwapAnxious {}
+        InterMachineSwapAnxious {
+            candidate_list: None,
+        }
+    }
+
+    /// Creates an instance that only evaluates the swaps that put one of the tasks
+    /// next to one of its candidates, keeping the specified number of candidates for each task
+    pub fn with_candidate_list(size: usize) -> Self {
+        InterMachineSwapAnxious {
+            candidate_list: Some(CandidateListCache::new(size)),
+        }
+    }
+
+    fn is_candidate(
+        candidate_list: Option<&CandidateList>,
+        solution: &ProblemSolution,
+        from_machine: usize,
+        task_index: usize,
+        to_machine: usize,
+        possible_task_index: usize,
+    ) -> bool {
+        let from_tasks = &solution.task_assignment_matrix[from_machine];
+        let to_tasks = &solution.task_assignment_matrix[to_machine];
+        match candidate_list {
+            Some(candidate_list) => {
+                candidate_list.allows_replacement(
+                    from_tasks[task_index],
+                    to_tasks,
+                    possible_task_index,
+                ) || candidate_list.allows_replacement(
+                    to_tasks[possible_task_index],
+                    from_tasks,
+                    task_index,
+                )
+            }
+            None => true,
+        }
     }
 
     fn get_solution(
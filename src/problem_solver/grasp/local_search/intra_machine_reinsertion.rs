@@ -8,27 +8,34 @@ impl LocalSearch for IntraMachineReinsertion {
         &self,
         instance: &ProblemInstance,
         solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let number_of_tasks = instance.task_times().len();
+        self.perform_search_with_bits(instance, solution, &mut DontLookBits::new(number_of_tasks))
+    }
+
+    fn perform_search_with_bits(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        dont_look_bits: &mut DontLookBits,
     ) -> ProblemSolution {
         let solution_ref = &solution;
-        (0..solution.task_assignment_matrix.len())
-            .filter(|&machine| solution.task_assignment_matrix[machine].len() > 1)
-            .flat_map(|machine| {
-                (0..solution.task_assignment_matrix[machine].len()).flat_map(move |task_index| {
-                    (0..=solution_ref.task_assignment_matrix[machine].len())
-                        .filter(move |&possible_index| possible_index != task_index)
-                        .map(move |possible_task_index| {
-                            IntraMachineReinsertion::get_solution(
-                                instance,
-                                solution_ref,
-                                machine,
-                                task_index,
-                                possible_task_index,
-                            )
-                        })
-                })
-            })
-            .min_by_key(|solution| solution.get_total_completion_time())
-            .unwrap_or(solution)
+        best_move_by_task(solution_ref, dont_look_bits, |machine, task_index| {
+            self.moves(instance, solution_ref, machine, task_index)
+        })
+        .unwrap_or(solution)
+    }
+}
+
+impl Neighbourhood for IntraMachineReinsertion {
+    fn neighbours(
+        &self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+    ) -> Vec<ProblemSolution> {
+        all_moves(solution, |machine, task_index| {
+            self.moves(instance, solution, machine, task_index)
+        })
     }
 }
 
@@ -37,6 +44,26 @@ impl IntraMachineReinsertion {
         IntraMachineReinsertion {}
     }
 
+    fn moves<'a>(
+        &'a self,
+        instance: &'a ProblemInstance,
+        solution: &'a ProblemSolution,
+        machine: usize,
+        task_index: usize,
+    ) -> impl Iterator<Item = ProblemSolution> + 'a {
+        (0..=solution.task_assignment_matrix[machine].len())
+            .filter(move |&possible_index| possible_index != task_index)
+            .map(move |possible_task_index| {
+                IntraMachineReinsertion::get_solution(
+                    instance,
+                    solution,
+                    machine,
+                    task_index,
+                    possible_task_index,
+                )
+            })
+    }
+
     fn get_solution(
         instance: &ProblemInstance,
         solution: &ProblemSolution,
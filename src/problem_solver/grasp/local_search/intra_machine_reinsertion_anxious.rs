@@ -8,6 +8,16 @@ impl LocalSearch for IntraMachineReinsertionAnxious {
         &self,
         instance: &ProblemInstance,
         solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let number_of_tasks = instance.task_times().len();
+        self.perform_search_with_bits(instance, solution, &mut DontLookBits::new(number_of_tasks))
+    }
+
+    fn perform_search_with_bits(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        dont_look_bits: &mut DontLookBits,
     ) -> ProblemSolution {
         let solution_tct = solution.get_total_completion_time();
         for machine in 0..solution.task_assignment_matrix.len() {
@@ -15,6 +25,10 @@ impl LocalSearch for IntraMachineReinsertionAnxious {
                 continue;
             }
             for task_index in 0..solution.task_assignment_matrix[machine].len() {
+                let task = solution.task_assignment_matrix[machine][task_index];
+                if dont_look_bits.is_set(task) {
+                    continue;
+                }
                 for possible_task_index in 0..=solution.task_assignment_matrix[machine].len() {
                     if task_index == possible_task_index {
                         continue;
@@ -30,6 +44,7 @@ impl LocalSearch for IntraMachineReinsertionAnxious {
                         return new_solution;
                     }
                 }
+                dont_look_bits.set(task);
             }
         }
         solution
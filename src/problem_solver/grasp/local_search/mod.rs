@@ -1,5 +1,9 @@
 use super::{ProblemInstance, ProblemSolution};
 
+mod candidate_list;
+mod cyclic_exchange;
+mod cyclic_vnd;
+mod dont_look_bits;
 mod inter_machine_reinsertion;
 mod inter_machine_reinsertion_anxious;
 mod inter_machine_swap;
@@ -9,6 +13,16 @@ mod intra_machine_reinsertion_anxious;
 mod intra_machine_swap;
 mod intra_machine_swap_anxious;
 mod no_search;
+mod pipe_vnd;
+mod random_neighbourhood;
+mod repeat;
+mod sequence;
+mod union_vnd;
+mod vnd;
+use candidate_list::{CandidateList, CandidateListCache};
+pub use cyclic_exchange::CyclicExchange;
+pub use cyclic_vnd::CyclicVnd;
+pub use dont_look_bits::DontLookBits;
 pub use inter_machine_reinsertion::InterMachineReinsertion;
 pub use inter_machine_reinsertion_anxious::InterMachineReinsertionAnxious;
 pub use inter_machine_swap::InterMachineSwap;
@@ -18,6 +32,12 @@ pub use intra_machine_reinsertion_anxious::IntraMachineReinsertionAnxious;
 pub use intra_machine_swap::IntraMachineSwap;
 pub use intra_machine_swap_anxious::IntraMachineSwapAnxious;
 pub use no_search::NoSearch;
+pub use pipe_vnd::PipeVnd;
+pub use random_neighbourhood::RandomNeighbourhood;
+pub use repeat::Repeat;
+pub use sequence::Sequence;
+pub use union_vnd::UnionVnd;
+pub use vnd::Vnd;
 
 /// A trait that specifies how a local search should behave. A local search
 /// should search for better solutions inside an specific environment and
@@ -27,27 +47,147 @@ pub use no_search::NoSearch;
 /// one to keep exploring
 /// * Anxious: They move to a new solution as soon as they have found a better one
 pub trait LocalSearch {
-    /// Performs a local search that stops when there isn't a better solution
-    fn improve(
+    /// Performs a local search that stops when there isn't a better solution. The same
+    /// don't look bits are kept during the whole descent
+    fn improve(&self, instance: &ProblemInstance, solution: ProblemSolution) -> ProblemSolution {
+        let mut dont_look_bits = DontLookBits::new(instance.task_times().len());
+        self.improve_with_bits(instance, solution, &mut dont_look_bits)
+    }
+
+    /// Performs a local search **only** in the environment of the actual solution.
+    /// It can return a solution that **might** be a better one than the actual
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution;
+
+    /// Performs a local search that stops when there isn't a better solution, using
+    /// the don't look bits to skip the tasks whose moves didn't improve the solution.
+    /// After each accepted move only the bits of the tasks in the touched machines are reset
+    fn improve_with_bits(
         &self,
         instance: &ProblemInstance,
         mut solution: ProblemSolution,
+        dont_look_bits: &mut DontLookBits,
     ) -> ProblemSolution {
         loop {
-            let another_solution = self.perform_search(instance, solution.clone());
+            let another_solution =
+                self.perform_search_with_bits(instance, solution.clone(), dont_look_bits);
             if another_solution.get_total_completion_time() >= solution.get_total_completion_time()
             {
                 return solution;
             }
+            dont_look_bits.reset_changed_machines(&solution, &another_solution);
             solution = another_solution;
         }
     }
 
-    /// Performs a local search **only** in the environment of the actual solution.
-    /// It can return a solution that **might** be a better one than the actual
+    /// Performs a local search **only** in the environment of the actual solution,
+    /// skipping the moves of the tasks whose don't look bit is set. The bit of the tasks
+    /// whose moves don't improve the solution must be set. By default the bits are ignored
+    fn perform_search_with_bits(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        _dont_look_bits: &mut DontLookBits,
+    ) -> ProblemSolution {
+        self.perform_search(instance, solution)
+    }
+}
+
+/// An environment whose solutions can be listed. It is implemented by the greedy local
+/// searches that move a single task, so metaheuristics like tabu search can choose among
+/// all the solutions of the environment and not only the better ones
+pub trait Neighbourhood {
+    /// Returns every solution in the environment of the actual solution, even the worse ones
+    fn neighbours(
+        &self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+    ) -> Vec<ProblemSolution>;
+}
+
+impl<L: LocalSearch + ?Sized> LocalSearch for Box<L> {
+    fn improve(&self, instance: &ProblemInstance, solution: ProblemSolution) -> ProblemSolution {
+        (**self).improve(instance, solution)
+    }
+
     fn perform_search(
         &self,
         instance: &ProblemInstance,
         solution: ProblemSolution,
-    ) -> ProblemSolution;
+    ) -> ProblemSolution {
+        (**self).perform_search(instance, solution)
+    }
+
+    fn improve_with_bits(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        dont_look_bits: &mut DontLookBits,
+    ) -> ProblemSolution {
+        (**self).improve_with_bits(instance, solution, dont_look_bits)
+    }
+
+    fn perform_search_with_bits(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        dont_look_bits: &mut DontLookBits,
+    ) -> ProblemSolution {
+        (**self).perform_search_with_bits(instance, solution, dont_look_bits)
+    }
+}
+
+/// Explores the moves of each task whose don't look bit isn't set and returns the best
+/// solution found if it's better than the actual one. The moves of a task are generated
+/// with its machine and its index in that machine. The bit of the tasks without any
+/// improving move gets set
+fn best_move_by_task<F, I>(
+    solution: &ProblemSolution,
+    dont_look_bits: &mut DontLookBits,
+    moves: F,
+) -> Option<ProblemSolution>
+where
+    F: Fn(usize, usize) -> I,
+    I: Iterator<Item = ProblemSolution>,
+{
+    let solution_tct = solution.get_total_completion_time();
+    let mut best_solution: Option<ProblemSolution> = None;
+    for machine in 0..solution.task_assignment_matrix.len() {
+        for task_index in 0..solution.task_assignment_matrix[machine].len() {
+            let task = solution.task_assignment_matrix[machine][task_index];
+            if dont_look_bits.is_set(task) {
+                continue;
+            }
+            let best_tct = best_solution
+                .as_ref()
+                .map_or(solution_tct, |best| best.get_total_completion_time());
+            match moves(machine, task_index).min_by_key(|new| new.get_total_completion_time()) {
+                Some(new_solution) if new_solution.get_total_completion_time() < best_tct => {
+                    best_solution = Some(new_solution)
+                }
+                Some(new_solution) if new_solution.get_total_completion_time() < solution_tct => {}
+                _ => dont_look_bits.set(task),
+            }
+        }
+    }
+    best_solution
+}
+
+/// Collects the moves of every task of the solution. The moves of a task are generated
+/// with its machine and its index in that machine
+fn all_moves<F, I>(solution: &ProblemSolution, moves: F) -> Vec<ProblemSolution>
+where
+    F: Fn(usize, usize) -> I,
+    I: Iterator<Item = ProblemSolution>,
+{
+    solution
+        .task_assignment_matrix
+        .iter()
+        .enumerate()
+        .flat_map(|(machine, tasks)| (0..tasks.len()).map(move |task_index| (machine, task_index)))
+        .flat_map(|(machine, task_index)| moves(machine, task_index))
+        .collect()
 }
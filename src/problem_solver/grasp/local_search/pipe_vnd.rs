@@ -0,0 +1,49 @@
+use super::*;
+
+/// A pipe variable neighbourhood descent. Unlike [Vnd](Vnd), when an environment
+/// improves the solution it keeps searching in it until it reaches a local minimum
+/// and then moves to the next one. It stops when a whole pass over the environments
+/// doesn't improve the solution
+pub struct PipeVnd {
+    searches: Vec<Box<dyn LocalSearch>>,
+}
+
+impl LocalSearch for PipeVnd {
+    fn improve(&self, instance: &ProblemInstance, solution: ProblemSolution) -> ProblemSolution {
+        self.perform_search(instance, solution)
+    }
+
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let mut dont_look_bits =
+            DontLookBits::per_search(instance.task_times().len(), self.searches.len());
+        loop {
+            let mut improved = false;
+            for search_index in 0..self.searches.len() {
+                let new_solution = self.searches[search_index].improve_with_bits(
+                    instance,
+                    solution.clone(),
+                    &mut dont_look_bits[search_index],
+                );
+                if new_solution.get_total_completion_time() < solution.get_total_completion_time() {
+                    DontLookBits::reset_all_changed(&mut dont_look_bits, &solution, &new_solution);
+                    solution = new_solution;
+                    improved = true;
+                }
+            }
+            if !improved {
+                return solution;
+            }
+        }
+    }
+}
+
+impl PipeVnd {
+    /// Creates a new instance with the specified environments
+    pub fn new(searches: Vec<Box<dyn LocalSearch>>) -> Self {
+        PipeVnd { searches }
+    }
+}
@@ -0,0 +1,35 @@
+use super::*;
+use rand::seq::SliceRandom;
+
+/// A local search that explores the environments in a random order each time and moves
+/// to the first better solution found, so it only stops when none of the environments
+/// can improve the solution
+pub struct RandomNeighbourhood {
+    searches: Vec<Box<dyn LocalSearch>>,
+}
+
+impl LocalSearch for RandomNeighbourhood {
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let mut order: Vec<usize> = (0..self.searches.len()).collect();
+        order.shuffle(&mut rand::thread_rng());
+        for search_index in order {
+            let new_solution =
+                self.searches[search_index].perform_search(instance, solution.clone());
+            if new_solution.get_total_completion_time() < solution.get_total_completion_time() {
+                return new_solution;
+            }
+        }
+        solution
+    }
+}
+
+impl RandomNeighbourhood {
+    /// Creates a new instance with the specified environments
+    pub fn new(searches: Vec<Box<dyn LocalSearch>>) -> Self {
+        RandomNeighbourhood { searches }
+    }
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+/// A local search that performs at most a fixed number of steps of another local
+/// search, so it can stop before reaching a local minimum
+pub struct Repeat<L: LocalSearch> {
+    times: usize,
+    local_search: L,
+}
+
+impl<L: LocalSearch> LocalSearch for Repeat<L> {
+    fn improve(&self, instance: &ProblemInstance, solution: ProblemSolution) -> ProblemSolution {
+        self.perform_search(instance, solution)
+    }
+
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+    ) -> ProblemSolution {
+        for _ in 0..self.times {
+            let new_solution = self.local_search.perform_search(instance, solution.clone());
+            if new_solution.get_total_completion_time() >= solution.get_total_completion_time() {
+                break;
+            }
+            solution = new_solution;
+        }
+        solution
+    }
+}
+
+impl<L: LocalSearch> Repeat<L> {
+    /// Creates a new instance that will perform the specified number of steps
+    pub fn new(times: usize, local_search: L) -> Self {
+        Repeat {
+            times,
+            local_search,
+        }
+    }
+}
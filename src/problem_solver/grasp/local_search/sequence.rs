@@ -0,0 +1,26 @@
+use super::*;
+
+/// A local search that combines two local searches, improving the solution with the
+/// first one until it reaches a local minimum and then with the second one
+pub struct Sequence<A: LocalSearch, B: LocalSearch> {
+    first: A,
+    second: B,
+}
+
+impl<A: LocalSearch, B: LocalSearch> LocalSearch for Sequence<A, B> {
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let solution = self.first.improve(instance, solution);
+        self.second.improve(instance, solution)
+    }
+}
+
+impl<A: LocalSearch, B: LocalSearch> Sequence<A, B> {
+    /// Creates a new instance that will apply the searches in the specified order
+    pub fn new(first: A, second: B) -> Self {
+        Sequence { first, second }
+    }
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+/// A variable neighbourhood descent that explores the union of all the environments.
+/// In each step it searches in every environment and moves to the best solution found
+/// among all of them, so it stops when none of them can improve the solution
+pub struct UnionVnd {
+    searches: Vec<Box<dyn LocalSearch>>,
+}
+
+impl LocalSearch for UnionVnd {
+    fn improve(&self, instance: &ProblemInstance, solution: ProblemSolution) -> ProblemSolution {
+        self.perform_search(instance, solution)
+    }
+
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let mut dont_look_bits =
+            DontLookBits::per_search(instance.task_times().len(), self.searches.len());
+        loop {
+            let best_solution = self
+                .searches
+                .iter()
+                .zip(dont_look_bits.iter_mut())
+                .map(|(search, bits)| {
+                    search.perform_search_with_bits(instance, solution.clone(), bits)
+                })
+                .min_by_key(|new_solution| new_solution.get_total_completion_time());
+            match best_solution {
+                Some(new_solution)
+                    if new_solution.get_total_completion_time()
+                        < solution.get_total_completion_time() =>
+                {
+                    DontLookBits::reset_all_changed(&mut dont_look_bits, &solution, &new_solution);
+                    solution = new_solution;
+                }
+                _ => return solution,
+            }
+        }
+    }
+}
+
+impl UnionVnd {
+    /// Creates a new instance with the specified environments
+    pub fn new(searches: Vec<Box<dyn LocalSearch>>) -> Self {
+        UnionVnd { searches }
+    }
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+/// A variable neighbourhood descent. It searches in the first environment and
+/// whenever a better solution is found it starts again from the first one. If an
+/// environment doesn't improve the solution it moves to the next one, so it stops
+/// when the solution is a local minimum for all the environments. The order of the
+/// searches in the vector will be preserved and can affect the performance
+pub struct Vnd {
+    searches: Vec<Box<dyn LocalSearch>>,
+}
+
+impl LocalSearch for Vnd {
+    fn improve(&self, instance: &ProblemInstance, solution: ProblemSolution) -> ProblemSolution {
+        // A single search already ends in a local minimum for every environment, the
+        // other descents over several environments rely on this too
+        self.perform_search(instance, solution)
+    }
+
+    fn perform_search(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+    ) -> ProblemSolution {
+        let mut dont_look_bits =
+            DontLookBits::per_search(instance.task_times().len(), self.searches.len());
+        let mut search_index = 0;
+        while search_index < self.searches.len() {
+            let new_solution = self.searches[search_index].perform_search_with_bits(
+                instance,
+                solution.clone(),
+                &mut dont_look_bits[search_index],
+            );
+            if solution.get_total_completion_time() <= new_solution.get_total_completion_time() {
+                search_index += 1;
+            } else {
+                DontLookBits::reset_all_changed(&mut dont_look_bits, &solution, &new_solution);
+                solution = new_solution;
+                search_index = 0;
+            }
+        }
+        solution
+    }
+}
+
+impl Vnd {
+    /// Creates a new instance with the specified environments
+    pub fn new(searches: Vec<Box<dyn LocalSearch>>) -> Self {
+        Vnd { searches }
+    }
+}
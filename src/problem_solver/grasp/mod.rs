@@ -1,28 +1,42 @@
 use super::{ProblemInstance, ProblemSolution, ProblemSolver, RandomizedGreedySolver};
 
 pub mod local_search;
+mod path_relinking;
+mod reactive_grasp;
 pub mod stop_criterion;
 use local_search::LocalSearch;
+pub use path_relinking::{PathRelinking, RelinkingDirection};
+pub use reactive_grasp::ReactiveGRASP;
 use stop_criterion::StopCriterion;
 
 /// A implementation of a GRASP algorithm. The stop
 /// criterion and the local search to be used can be chosen and passed to the
 /// constructor. For the constructive phase it will use the
 /// [Randomized greedy solver algorithm](super::RandomizedGreedySolver), the k can
-/// also be passed as an argument in the constructor
+/// also be passed as an argument in the constructor. Optionally, each local optimum
+/// can be relinked with the solutions of an elite pool using
+/// [path relinking](PathRelinking), and a cheap local search can be applied to the partial
+/// solution every few insertions of the constructive phase, following the proximate
+/// optimality principle. Both are enabled with chained calls after the constructor
 pub struct GRASP<L: LocalSearch, S: StopCriterion> {
     size_to_choose_from: usize,
     local_search: L,
     stop_criterion: S,
+    path_relinking: Option<PathRelinking>,
+    // The number of insertions between searches and the search
+    partial_search: Option<(usize, Box<dyn LocalSearch>)>,
 }
 
 impl<L: LocalSearch, S: StopCriterion> ProblemSolver for GRASP<L, S> {
     fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
         let mut solver = RandomizedGreedySolver::new(self.size_to_choose_from);
-        let mut solution = self.local_search.improve(instance, solver.solve(instance));
+        if let Some(path_relinking) = &mut self.path_relinking {
+            path_relinking.reset();
+        }
+        let mut solution = self.local_optimum(instance, &mut solver);
         let mut solution_tct = solution.get_total_completion_time();
         loop {
-            let new_solution = self.local_search.improve(instance, solver.solve(instance));
+            let new_solution = self.local_optimum(instance, &mut solver);
             let new_solution_tct = new_solution.get_total_completion_time();
             if self.stop_criterion.stop(solution_tct, new_solution_tct) {
                 if solution_tct <= new_solution_tct {
@@ -47,6 +61,44 @@ impl<L: LocalSearch, S: StopCriterion> GRASP<L, S> {
             size_to_choose_from,
             local_search,
             stop_criterion,
+            path_relinking: None,
+            partial_search: None,
+        }
+    }
+
+    /// Makes the GRASP relink each local optimum with the solutions of the elite pool of
+    /// the path relinking
+    pub fn path_relinking(mut self, path_relinking: PathRelinking) -> Self {
+        self.path_relinking = Some(path_relinking);
+        self
+    }
+
+    /// Makes the GRASP improve the partial solution of the constructive phase with the
+    /// partial search each time the specified number of tasks has been added. The period
+    /// must be greater than 0
+    pub fn partial_search(mut self, period: usize, partial_search: Box<dyn LocalSearch>) -> Self {
+        assert!(period > 0);
+        self.partial_search = Some((period, partial_search));
+        self
+    }
+
+    fn local_optimum(
+        &mut self,
+        instance: &ProblemInstance,
+        solver: &mut RandomizedGreedySolver,
+    ) -> ProblemSolution {
+        let solution = match &self.partial_search {
+            Some((period, partial_search)) => {
+                solver.solve_with_improvement(instance, *period, |solution| {
+                    partial_search.improve(instance, solution)
+                })
+            }
+            None => solver.solve(instance),
+        };
+        let solution = self.local_search.improve(instance, solution);
+        match &mut self.path_relinking {
+            Some(path_relinking) => path_relinking.relink(instance, solution, &self.local_search),
+            None => solution,
         }
     }
 }
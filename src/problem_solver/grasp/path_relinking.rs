@@ -0,0 +1,188 @@
+use super::{LocalSearch, ProblemInstance, ProblemSolution};
+
+/// The path relinking phase of a [GRASP](super::GRASP). It keeps a pool with the best and
+/// most diverse solutions found. Each new local optimum is relinked with a random solution
+/// of the pool: the walk moves in each step the task that gives the best TCT to the machine
+/// and position it has in the guiding solution, and each task is moved at most once. The
+/// best intermediate solution of the walk is improved with the local search of the GRASP.
+///
+/// A solution enters the pool if it's better than the best one of the pool, or if the pool
+/// isn't full or it's better than the worst one, and its distance to every member is at
+/// least the min distance. When the pool is full it replaces the worst member
+pub struct PathRelinking {
+    pool_size: usize,
+    min_distance: usize,
+    direction: RelinkingDirection,
+    pool: Vec<ProblemSolution>,
+}
+
+/// The direction of the walks of the [PathRelinking](PathRelinking)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RelinkingDirection {
+    /// Walks from the new local optimum to the solution of the pool
+    Forward,
+    /// Walks from the solution of the pool to the new local optimum
+    Backward,
+    /// Walks from both solutions at the same time, alternating the steps, until they meet
+    Mixed,
+}
+
+impl PathRelinking {
+    /// Creates a new instance with the specified arguments. The pool must have at least one
+    /// solution and the min distance must be at least 1, so the pool has no copies
+    pub fn new(pool_size: usize, min_distance: usize, direction: RelinkingDirection) -> Self {
+        assert!(pool_size > 0 && min_distance > 0);
+        PathRelinking {
+            pool_size,
+            min_distance,
+            direction,
+            pool: Vec::with_capacity(pool_size),
+        }
+    }
+
+    /// Empties the pool, so the solutions of another run aren't used
+    pub(super) fn reset(&mut self) {
+        self.pool.clear();
+    }
+
+    /// Relinks the solution with a random solution of the pool, adds the result to the
+    /// pool and returns it. The result is never worse than the solution
+    pub(super) fn relink<L: LocalSearch>(
+        &mut self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        local_search: &L,
+    ) -> ProblemSolution {
+        let mut best_solution = solution;
+        if !self.pool.is_empty() {
+            let elite = &self.pool[rand::random::<usize>() % self.pool.len()];
+            let walk_best = match self.direction {
+                RelinkingDirection::Forward => walk(instance, &best_solution, elite, false),
+                RelinkingDirection::Backward => walk(instance, elite, &best_solution, false),
+                RelinkingDirection::Mixed => walk(instance, &best_solution, elite, true),
+            };
+            if let Some(walk_best) = walk_best {
+                let walk_best = local_search.improve(instance, walk_best);
+                if walk_best.get_total_completion_time() < best_solution.get_total_completion_time()
+                {
+                    best_solution = walk_best;
+                }
+            }
+        }
+        self.add(best_solution.clone());
+        best_solution
+    }
+
+    fn add(&mut self, solution: ProblemSolution) {
+        let tct = solution.get_total_completion_time();
+        let (worst, worst_tct) = match self
+            .pool
+            .iter()
+            .map(ProblemSolution::get_total_completion_time)
+            .enumerate()
+            .max_by_key(|&(_, tct)| tct)
+        {
+            Some(worst) => worst,
+            None => {
+                self.pool.push(solution);
+                return;
+            }
+        };
+        let best_tct = self
+            .pool
+            .iter()
+            .map(ProblemSolution::get_total_completion_time)
+            .min()
+            .unwrap();
+        let diverse = self
+            .pool
+            .iter()
+            .all(|member| member.distance(&solution) >= self.min_distance);
+        let admitted =
+            tct < best_tct || (diverse && (self.pool.len() < self.pool_size || tct < worst_tct));
+        if !admitted {
+            return;
+        }
+        if self.pool.len() < self.pool_size {
+            self.pool.push(solution);
+        } else {
+            self.pool[worst] = solution;
+        }
+    }
+}
+
+/// Walks from the initial solution to the guiding one and returns the best intermediate
+/// solution, if there is any. If it's mixed, after each step the walk continues from the
+/// other end, so the guiding solution also moves towards the initial one
+fn walk(
+    instance: &ProblemInstance,
+    initial: &ProblemSolution,
+    guide: &ProblemSolution,
+    mixed: bool,
+) -> Option<ProblemSolution> {
+    let mut moved_tasks = vec![false; instance.task_times().len()];
+    let mut actual = initial.clone();
+    let mut guide = guide.clone();
+    let mut best_solution: Option<ProblemSolution> = None;
+    loop {
+        let positions = get_positions(&guide, moved_tasks.len());
+        let next = actual
+            .task_assignment_matrix
+            .iter()
+            .enumerate()
+            .flat_map(|(machine, tasks)| {
+                tasks
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, &task)| (task, (machine, index)))
+            })
+            .filter(|&(task, position)| !moved_tasks[task] && positions[task] != position)
+            .map(|(task, from)| (task, move_task(instance, &actual, from, positions[task])))
+            .min_by_key(|(_, solution)| solution.get_total_completion_time());
+        let (task, next) = match next {
+            Some(next) => next,
+            None => return best_solution,
+        };
+        moved_tasks[task] = true;
+        if best_solution
+            .as_ref()
+            .is_none_or(|best| next.get_total_completion_time() < best.get_total_completion_time())
+        {
+            best_solution = Some(next.clone());
+        }
+        actual = next;
+        if mixed {
+            std::mem::swap(&mut actual, &mut guide);
+        }
+    }
+}
+
+/// Returns the machine and the index of each task
+fn get_positions(solution: &ProblemSolution, number_of_tasks: usize) -> Vec<(usize, usize)> {
+    let mut positions = vec![(0, 0); number_of_tasks];
+    for (machine, tasks) in solution.task_assignment_matrix.iter().enumerate() {
+        for (index, &task) in tasks.iter().enumerate() {
+            positions[task] = (machine, index);
+        }
+    }
+    positions
+}
+
+/// Moves a task to the specified machine and index, or to the end of the machine if it
+/// doesn't have that many tasks
+fn move_task(
+    instance: &ProblemInstance,
+    solution: &ProblemSolution,
+    (from_machine, from_index): (usize, usize),
+    (to_machine, to_index): (usize, usize),
+) -> ProblemSolution {
+    let mut new_solution = solution.clone();
+    let task = new_solution.task_assignment_matrix[from_machine].remove(from_index);
+    let to_index = to_index.min(new_solution.task_assignment_matrix[to_machine].len());
+    new_solution.task_assignment_matrix[to_machine].insert(to_index, task);
+    for &machine in &[from_machine, to_machine] {
+        new_solution.tcts_by_machine[machine] =
+            instance.calculate_total_completion_time(&new_solution.task_assignment_matrix[machine]);
+    }
+    new_solution
+}
@@ -0,0 +1,139 @@
+use super::{
+    LocalSearch, ProblemInstance, ProblemSolution, ProblemSolver, RandomizedGreedySolver,
+    StopCriterion,
+};
+
+/// A Reactive GRASP implementation. Instead of a fixed k for the
+/// [Randomized greedy solver algorithm](super::super::RandomizedGreedySolver), each
+/// iteration chooses one of the candidate values randomly. All of them start with the same
+/// probability, and every number of iterations the probability of each value is updated
+/// to be proportional to (best TCT / mean TCT of the value)^amplification, so the values
+/// that produce better solutions are chosen more often. The learned probabilities can be
+/// read after solving an instance
+pub struct ReactiveGRASP<L: LocalSearch, S: StopCriterion> {
+    sizes_to_choose_from: Vec<usize>,
+    update_period: usize,
+    amplification: f64,
+    local_search: L,
+    stop_criterion: S,
+    probabilities: Vec<f64>,
+}
+
+impl<L: LocalSearch, S: StopCriterion> ProblemSolver for ReactiveGRASP<L, S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let number_of_values = self.sizes_to_choose_from.len();
+        self.probabilities = vec![1.0 / number_of_values as f64; number_of_values];
+        let mut tct_sums = vec![0; number_of_values];
+        let mut times_chosen = vec![0; number_of_values];
+        let mut solution = self.iterate(instance, &mut tct_sums, &mut times_chosen);
+        let mut solution_tct = solution.get_total_completion_time();
+        let mut iterations = 1;
+        loop {
+            let new_solution = self.iterate(instance, &mut tct_sums, &mut times_chosen);
+            let new_solution_tct = new_solution.get_total_completion_time();
+            if self.stop_criterion.stop(solution_tct, new_solution_tct) {
+                if solution_tct <= new_solution_tct {
+                    return solution;
+                } else {
+                    return new_solution;
+                }
+            }
+            if new_solution_tct < solution_tct {
+                solution = new_solution;
+                solution_tct = new_solution_tct;
+            }
+            iterations += 1;
+            if iterations % self.update_period == 0 {
+                self.update_probabilities(solution_tct, &tct_sums, &times_chosen);
+            }
+        }
+    }
+}
+
+impl<L: LocalSearch, S: StopCriterion> ReactiveGRASP<L, S> {
+    /// Creates a new Reactive GRASP with the specified arguments. There must be at least
+    /// one candidate k, all of them greater than 0, the probabilities must be updated at
+    /// least every iteration and the amplification can't be negative
+    pub fn new(
+        sizes_to_choose_from: Vec<usize>,
+        update_period: usize,
+        amplification: f64,
+        local_search: L,
+        stop_criterion: S,
+    ) -> Self {
+        assert!(!sizes_to_choose_from.is_empty());
+        assert!(sizes_to_choose_from.iter().all(|&size| size > 0));
+        assert!(update_period > 0 && amplification >= 0.0);
+        let number_of_values = sizes_to_choose_from.len();
+        ReactiveGRASP {
+            sizes_to_choose_from,
+            update_period,
+            amplification,
+            local_search,
+            stop_criterion,
+            probabilities: vec![1.0 / number_of_values as f64; number_of_values],
+        }
+    }
+
+    /// Allows getting each candidate k with the probability it had at the end of the
+    /// last run
+    pub fn get_probabilities(&self) -> Vec<(usize, f64)> {
+        self.sizes_to_choose_from
+            .iter()
+            .copied()
+            .zip(self.probabilities.iter().copied())
+            .collect()
+    }
+
+    /// Builds a solution with a random k, improves it and adds its TCT to the ones
+    /// produced by that k
+    fn iterate(
+        &self,
+        instance: &ProblemInstance,
+        tct_sums: &mut [usize],
+        times_chosen: &mut [usize],
+    ) -> ProblemSolution {
+        let value = self.choose_value();
+        let solution = self.local_search.improve(
+            instance,
+            RandomizedGreedySolver::new(self.sizes_to_choose_from[value]).solve(instance),
+        );
+        tct_sums[value] += solution.get_total_completion_time();
+        times_chosen[value] += 1;
+        solution
+    }
+
+    fn choose_value(&self) -> usize {
+        let mut value = rand::random::<f64>();
+        for (index, probability) in self.probabilities.iter().enumerate() {
+            if value < *probability {
+                return index;
+            }
+            value -= probability;
+        }
+        self.probabilities.len() - 1
+    }
+
+    /// The values that haven't been chosen yet keep the quality of the best solution, so
+    /// they aren't discarded before being tried
+    fn update_probabilities(
+        &mut self,
+        best_tct: usize,
+        tct_sums: &[usize],
+        times_chosen: &[usize],
+    ) {
+        let qualities: Vec<f64> = tct_sums
+            .iter()
+            .zip(times_chosen)
+            .map(|(&tct_sum, &times)| {
+                if times == 0 {
+                    1.0
+                } else {
+                    (best_tct as f64 * times as f64 / tct_sum as f64).powf(self.amplification)
+                }
+            })
+            .collect();
+        let total: f64 = qualities.iter().sum();
+        self.probabilities = qualities.iter().map(|quality| quality / total).collect();
+    }
+}
@@ -1,10 +1,16 @@
-use super::{NewTask, ProblemInstance, ProblemSolution, ProblemSolver};
-use std::collections::HashSet;
+use super::{
+    InsertionCostTable, NewTask, ProblemInstance, ProblemSolution, ProblemSolver, Seeding,
+};
+use std::collections::{BinaryHeap, HashSet};
 
 /// A greedy algorithm that creates a solution for the problem by evaluating
 /// in each step the best position and machine to add a determined task to the
-/// solution
-pub struct GreedySolver {}
+/// solution. The insertions are cached and only the ones of the machine that changed are
+/// evaluated again in each step. The first task of each machine is chosen with the
+/// [seeding](super::Seeding)
+pub struct GreedySolver {
+    seeding: Seeding,
+}
 
 impl ProblemSolver for GreedySolver {
     fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
@@ -13,10 +19,9 @@ impl ProblemSolver for GreedySolver {
             tcts_by_machine: Vec::with_capacity(instance.number_of_machines()),
             task_assignment_matrix: Vec::with_capacity(instance.number_of_machines()),
         };
-        GreedySolver::choose_initial_tasks(&mut solution, instance, &mut asigned_tasks);
-        while asigned_tasks.len() < instance.task_times().len() {
-            GreedySolver::add_task(&mut solution, instance, &mut asigned_tasks);
-        }
+        self.seeding
+            .seed(instance, &mut solution, &mut asigned_tasks);
+        GreedySolver::complete(&mut solution, instance, &mut asigned_tasks);
         solution
     }
 }
@@ -24,48 +29,61 @@ impl ProblemSolver for GreedySolver {
 impl GreedySolver {
     /// Creates a new solver
     pub fn new() -> Self {
-        GreedySolver {}
+        GreedySolver::with_seeding(Seeding::CheapestFirst)
+    }
+
+    /// Creates a new solver that chooses the first task of each machine with the seeding
+    pub fn with_seeding(seeding: Seeding) -> Self {
+        GreedySolver { seeding }
     }
 
-    fn choose_initial_tasks(
+    /// Adds the tasks that haven't been assigned yet, one by one, in the task, machine and
+    /// position with the lowest TCT increment
+    pub(super) fn complete(
         solution: &mut ProblemSolution,
         instance: &ProblemInstance,
         asigned_tasks: &mut HashSet<usize>,
     ) {
-        for _ in 0..instance.number_of_machines() {
-            let task = instance.setup_times()[0]
-                .iter()
-                .skip(1) // Skip the starting setup times column
-                .enumerate()
-                .filter(|(index, _)| !asigned_tasks.contains(index))
-                .map(|(index, setup)| (index, setup + instance.task_times()[index]))
-                .min_by_key(|(_, cost)| *cost);
-            let (task, tct_increment) = match task {
-                Some(values) => values,
-                None => break,
-            };
-            solution.task_assignment_matrix.push(vec![task]);
-            solution.tcts_by_machine.push(tct_increment);
-            asigned_tasks.insert(task);
+        let mut insertion_costs = InsertionCostTable::new(instance, solution, asigned_tasks);
+        while let Some(new_task) = insertion_costs.get_best_new_task() {
+            insertion_costs.insert_task(instance, solution, asigned_tasks, &new_task);
         }
     }
 
-    fn add_task(
+    /// Adds the new task to the solution
+    pub(super) fn insert_task(
         solution: &mut ProblemSolution,
-        instance: &ProblemInstance,
         asigned_tasks: &mut HashSet<usize>,
+        new_task: &NewTask,
     ) {
-        let new_task = GreedySolver::get_best_new_task(solution, instance, asigned_tasks);
         solution.task_assignment_matrix[new_task.machine].insert(new_task.position, new_task.task);
         asigned_tasks.insert(new_task.task);
-        solution.tcts_by_machine[new_task.machine] += new_task.tct_increment;
+        // The increment can be negative if the setup times don't satisfy the triangle
+        // inequality
+        solution.tcts_by_machine[new_task.machine] =
+            (solution.tcts_by_machine[new_task.machine] as isize + new_task.tct_increment) as usize;
     }
 
-    fn get_best_new_task(
+    /// Returns the specified number of task, machine and position combinations with the
+    /// lowest TCT increment, sorted from the lowest one
+    pub(super) fn get_best_new_tasks(
         solution: &ProblemSolution,
         instance: &ProblemInstance,
         asigned_tasks: &HashSet<usize>,
-    ) -> NewTask {
+        number_of_tasks: usize,
+    ) -> Vec<NewTask> {
+        let mut new_tasks = GreedySolver::get_new_tasks(solution, instance, asigned_tasks);
+        (0..number_of_tasks)
+            .map_while(|_| new_tasks.pop())
+            .collect()
+    }
+
+    /// Evaluates every task, machine and position combination for the unassigned tasks
+    pub(super) fn get_new_tasks(
+        solution: &ProblemSolution,
+        instance: &ProblemInstance,
+        asigned_tasks: &HashSet<usize>,
+    ) -> BinaryHeap<NewTask> {
         (0..instance.number_of_machines())
             .flat_map(|machine| {
                 (0..instance.task_times().len())
@@ -78,12 +96,11 @@ impl GreedySolver {
                         })
                     })
             })
-            .min_by_key(|new_task| new_task.tct_increment)
-            // Panics if all the tasks have been asigned. This function shouldn't be called in such cases
-            .unwrap()
+            .collect()
     }
 
-    fn get_new_solution(
+    /// Evaluates the insertion of the task in the specified machine and position
+    pub(super) fn get_new_solution(
         solution: &ProblemSolution,
         instance: &ProblemInstance,
         task: usize,
@@ -92,8 +109,8 @@ impl GreedySolver {
     ) -> NewTask {
         let mut task_list = solution.task_assignment_matrix[machine].clone();
         task_list.insert(position, task);
-        let tct_increment = instance.calculate_total_completion_time(&task_list)
-            - solution.tcts_by_machine[machine];
+        let tct_increment = instance.calculate_total_completion_time(&task_list) as isize
+            - solution.tcts_by_machine[machine] as isize;
         NewTask {
             task,
             position,
@@ -0,0 +1,62 @@
+use super::*;
+
+/// A basic VNS implementation. It works like the [GVNS](GVNS) but it improves each
+/// shaken solution with a single local search instead of a variable neighbourhood descent
+pub struct BasicVNS<L: LocalSearch, S: StopCriterion> {
+    max_k: usize,
+    stop_criterion: S,
+    shakes: Vec<Box<dyn Shake>>,
+    local_search: L,
+    initial_solution: InitialSolution,
+}
+
+impl<L: LocalSearch, S: StopCriterion> ProblemSolver for BasicVNS<L, S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let BasicVNS {
+            max_k,
+            stop_criterion,
+            shakes,
+            local_search,
+            initial_solution,
+        } = self;
+        restart_loop(stop_criterion, |best_solution| {
+            let mut solution = initial_solution.get(instance, best_solution);
+            let mut k = 1;
+            while k <= *max_k {
+                let mut new_solution = shake_environment(shakes, instance, solution.clone(), k);
+                new_solution = local_search.improve(instance, new_solution);
+                if solution.get_total_completion_time() <= new_solution.get_total_completion_time()
+                {
+                    k += 1;
+                } else {
+                    solution = new_solution;
+                    k = 1;
+                }
+            }
+            solution
+        })
+    }
+}
+
+impl<L: LocalSearch, S: StopCriterion> BasicVNS<L, S> {
+    /// Creates a new instance with the specified max k, stop criterion, shaking operators,
+    /// local search and initial solution. The max k must be greater than 0 and there must
+    /// be at least one shaking operator
+    pub fn new(
+        max_k: usize,
+        stop_criterion: S,
+        shakes: Vec<Box<dyn Shake>>,
+        local_search: L,
+        initial_solution: InitialSolution,
+    ) -> Self {
+        assert!(max_k > 0);
+        assert!(!shakes.is_empty());
+        BasicVNS {
+            max_k,
+            stop_criterion,
+            shakes,
+            local_search,
+            initial_solution,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use super::{ProblemInstance, ProblemSolution, ProblemSolver};
+
+/// The way in which a VNS gets the solution from which each one of its searches starts
+pub enum InitialSolution {
+    /// Builds each starting solution with the solver
+    Solver(Box<dyn ProblemSolver>),
+    /// Starts each search from the same solution, for example an external schedule checked
+    /// with [ProblemSolution::new](ProblemSolution::new)
+    Fixed(ProblemSolution),
+    /// Builds the first starting solution with the solver and then starts each search
+    /// from the best solution found so far
+    PreviousBest(Box<dyn ProblemSolver>),
+}
+
+impl InitialSolution {
+    /// Returns the solution from which the next search should start. The best solution
+    /// found so far must be passed if there is one
+    pub(super) fn get(
+        &mut self,
+        instance: &ProblemInstance,
+        best_solution: Option<&ProblemSolution>,
+    ) -> ProblemSolution {
+        match (self, best_solution) {
+            (InitialSolution::Fixed(solution), _) => solution.clone(),
+            (InitialSolution::PreviousBest(_), Some(best_solution)) => best_solution.clone(),
+            (InitialSolution::Solver(solver), _)
+            | (InitialSolution::PreviousBest(solver), None) => solver.solve(instance),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::{
+    grasp::{
+        local_search::{CyclicVnd, LocalSearch, PipeVnd, UnionVnd, Vnd},
+        stop_criterion::StopCriterion,
+    },
+    ProblemInstance, ProblemSolution, ProblemSolver,
+};
+
+mod basic_vns;
+mod initial_solution;
+mod reduced_vns;
+pub mod shake;
+mod skewed_vns;
+pub use basic_vns::BasicVNS;
+pub use initial_solution::InitialSolution;
+pub use reduced_vns::ReducedVNS;
+use shake::Shake;
+pub use skewed_vns::SkewedVNS;
+
+/// A VNS implementation. The different environments used and the stop criterion
+/// can be specified in the constructor. It creates new solutions until the stop
+/// criterion is fullfilled. For each solution it gets a starting solution as specified
+/// by its [InitialSolution](InitialSolution) and then shakes the solution in the k-th
+/// environment, performs local searches until it reaches a local minimum
+/// for every environment and if that solution is better than the actual one, it updates
+/// the solution. The k increases each time the solution found was worse or equal than the
+/// actual one, until a max value that can be specified in the costructor. The shaking
+/// environments are built with a list of [shaking operators](shake::Shake), so the k-th
+/// environment can use a different structure and not only a bigger perturbation. The way
+/// in which the local searches are combined is specified by a [VndVariant](VndVariant)
+pub struct GVNS<S: StopCriterion> {
+    vns: BasicVNS<Box<dyn LocalSearch>, S>,
+}
+
+/// The variable neighbourhood descent that the [GVNS](GVNS) uses to improve
+/// the shaken solutions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VndVariant {
+    /// Goes back to the first environment whenever a better solution is found. See [Vnd](Vnd)
+    Basic,
+    /// Keeps searching in the environment that improved the solution. See [PipeVnd](PipeVnd)
+    Pipe,
+    /// Continues with the next environment when a better solution is found.
+    /// See [CyclicVnd](CyclicVnd)
+    Cyclic,
+    /// Moves to the best solution among all the environments. See [UnionVnd](UnionVnd)
+    Union,
+}
+
+impl<S: StopCriterion> ProblemSolver for GVNS<S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        self.vns.solve(instance)
+    }
+}
+
+impl<S: StopCriterion> GVNS<S> {
+    /// Creates a new instance with the specified max k, stop criterion, shaking operators
+    /// and local searches. The max k must be greater than 0 and there must be at least one
+    /// shaking operator. The order of the searches in the vector will be preserved and can affect
+    /// the performance of the algorithm. The VND variant decides how the searches are combined
+    /// and the initial solution how the starting point of each search is obtained
+    pub fn new(
+        max_k: usize,
+        stop_criterion: S,
+        shakes: Vec<Box<dyn Shake>>,
+        searches: Vec<Box<dyn LocalSearch>>,
+        vnd_variant: VndVariant,
+        initial_solution: InitialSolution,
+    ) -> Self {
+        let vnd: Box<dyn LocalSearch> = match vnd_variant {
+            VndVariant::Basic => Box::new(Vnd::new(searches)),
+            VndVariant::Pipe => Box::new(PipeVnd::new(searches)),
+            VndVariant::Cyclic => Box::new(CyclicVnd::new(searches)),
+            VndVariant::Union => Box::new(UnionVnd::new(searches)),
+        };
+        GVNS {
+            vns: BasicVNS::new(max_k, stop_criterion, shakes, vnd, initial_solution),
+        }
+    }
+}
+
+/// Searches from the initial solution and then again from the best solution found, until
+/// the stop criterion is fullfilled. Returns the best solution found
+fn restart_loop<S, F>(stop_criterion: &mut S, mut search: F) -> ProblemSolution
+where
+    S: StopCriterion,
+    F: FnMut(Option<&ProblemSolution>) -> ProblemSolution,
+{
+    let mut solution = search(None);
+    let mut solution_tct = solution.get_total_completion_time();
+    loop {
+        let new_solution = search(Some(&solution));
+        let new_solution_tct = new_solution.get_total_completion_time();
+        if stop_criterion.stop(solution_tct, new_solution_tct) {
+            if solution_tct <= new_solution_tct {
+                return solution;
+            } else {
+                return new_solution;
+            }
+        }
+        if new_solution_tct < solution_tct {
+            solution = new_solution;
+            solution_tct = new_solution_tct;
+        }
+    }
+}
+
+/// Shakes the solution in the k-th environment, k starting at 1. The environments
+/// cycle through the shaking operators, so with L operators the k-th environment uses
+/// the operator number (k - 1) % L with a strength of (k - 1) / L + 1
+fn shake_environment(
+    shakes: &[Box<dyn Shake>],
+    instance: &ProblemInstance,
+    solution: ProblemSolution,
+    k: usize,
+) -> ProblemSolution {
+    shakes[(k - 1) % shakes.len()].shake(instance, solution, (k - 1) / shakes.len() + 1)
+}
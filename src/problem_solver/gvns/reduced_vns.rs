@@ -0,0 +1,58 @@
+use super::*;
+
+/// A reduced VNS implementation. It works like the [GVNS](GVNS) but it doesn't
+/// perform any local search, so each shaken solution is directly compared with the
+/// actual one. It is very fast, which makes it useful for big instances
+pub struct ReducedVNS<S: StopCriterion> {
+    max_k: usize,
+    stop_criterion: S,
+    shakes: Vec<Box<dyn Shake>>,
+    initial_solution: InitialSolution,
+}
+
+impl<S: StopCriterion> ProblemSolver for ReducedVNS<S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let ReducedVNS {
+            max_k,
+            stop_criterion,
+            shakes,
+            initial_solution,
+        } = self;
+        restart_loop(stop_criterion, |best_solution| {
+            let mut solution = initial_solution.get(instance, best_solution);
+            let mut k = 1;
+            while k <= *max_k {
+                let new_solution = shake_environment(shakes, instance, solution.clone(), k);
+                if solution.get_total_completion_time() <= new_solution.get_total_completion_time()
+                {
+                    k += 1;
+                } else {
+                    solution = new_solution;
+                    k = 1;
+                }
+            }
+            solution
+        })
+    }
+}
+
+impl<S: StopCriterion> ReducedVNS<S> {
+    /// Creates a new instance with the specified max k, stop criterion, shaking operators
+    /// and initial solution. The max k must be greater than 0 and there must be at least
+    /// one shaking operator
+    pub fn new(
+        max_k: usize,
+        stop_criterion: S,
+        shakes: Vec<Box<dyn Shake>>,
+        initial_solution: InitialSolution,
+    ) -> Self {
+        assert!(max_k > 0);
+        assert!(!shakes.is_empty());
+        ReducedVNS {
+            max_k,
+            stop_criterion,
+            shakes,
+            initial_solution,
+        }
+    }
+}
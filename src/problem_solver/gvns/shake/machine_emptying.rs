@@ -0,0 +1,51 @@
+use super::*;
+
+/// A shaking operator that empties a number of random machines equal to the strength,
+/// moving each one of their tasks to a random position of a random machine that
+/// hasn't been emptied. At least one machine always keeps its tasks
+#[derive(Default)]
+pub struct MachineEmptying {}
+
+impl Shake for MachineEmptying {
+    fn shake(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+        strength: usize,
+    ) -> ProblemSolution {
+        let mut machines: Vec<usize> = (0..solution.task_assignment_matrix.len())
+            .filter(|&machine| !solution.task_assignment_matrix[machine].is_empty())
+            .collect();
+        let mut emptied_machines = Vec::new();
+        for _ in 0..strength {
+            if machines.len() < 2 {
+                break;
+            }
+            let from_machine = machines.swap_remove(rand::random::<usize>() % machines.len());
+            emptied_machines.push(from_machine);
+            let possible_machines: Vec<usize> = (0..solution.task_assignment_matrix.len())
+                .filter(|machine| !emptied_machines.contains(machine))
+                .collect();
+            let tasks = std::mem::take(&mut solution.task_assignment_matrix[from_machine]);
+            for task in tasks {
+                let to_machine =
+                    possible_machines[rand::random::<usize>() % possible_machines.len()];
+                let to_pos = rand::random::<usize>()
+                    % (solution.task_assignment_matrix[to_machine].len() + 1);
+                solution.task_assignment_matrix[to_machine].insert(to_pos, task);
+            }
+        }
+        solution.tcts_by_machine = solution
+            .task_assignment_matrix
+            .iter()
+            .map(|tasks| instance.calculate_total_completion_time(tasks))
+            .collect();
+        solution
+    }
+}
+
+impl MachineEmptying {
+    pub fn new() -> Self {
+        MachineEmptying {}
+    }
+}
@@ -0,0 +1,26 @@
+use super::{ProblemInstance, ProblemSolution};
+
+mod machine_emptying;
+mod random_intra_move;
+mod random_reinsertion;
+mod random_swap;
+mod segment_scramble;
+pub use machine_emptying::MachineEmptying;
+pub use random_intra_move::RandomIntraMove;
+pub use random_reinsertion::RandomReinsertion;
+pub use random_swap::RandomSwap;
+pub use segment_scramble::SegmentScramble;
+
+/// A trait that specifies how a shaking operator should behave. A shaking operator
+/// perturbs a solution with random moves of a certain structure, so a VNS can escape
+/// from a local minimum. The strength specifies how big the perturbation should be,
+/// usually the number of random moves
+pub trait Shake {
+    /// Returns a random solution in the environment of the specified strength
+    fn shake(
+        &self,
+        instance: &ProblemInstance,
+        solution: ProblemSolution,
+        strength: usize,
+    ) -> ProblemSolution;
+}
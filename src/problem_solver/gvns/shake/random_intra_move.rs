@@ -0,0 +1,38 @@
+use super::*;
+
+/// A shaking operator that performs a number of random reinsertions of tasks in
+/// their own machine equal to the strength
+#[derive(Default)]
+pub struct RandomIntraMove {}
+
+impl Shake for RandomIntraMove {
+    fn shake(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+        strength: usize,
+    ) -> ProblemSolution {
+        let machines: Vec<usize> = (0..solution.task_assignment_matrix.len())
+            .filter(|&machine| solution.task_assignment_matrix[machine].len() > 1)
+            .collect();
+        if machines.is_empty() {
+            return solution;
+        }
+        for _ in 0..strength {
+            let machine = machines[rand::random::<usize>() % machines.len()];
+            let tasks = &mut solution.task_assignment_matrix[machine];
+            let task = tasks.remove(rand::random::<usize>() % tasks.len());
+            let position = rand::random::<usize>() % (tasks.len() + 1);
+            tasks.insert(position, task);
+            solution.tcts_by_machine[machine] =
+                instance.calculate_total_completion_time(&solution.task_assignment_matrix[machine]);
+        }
+        solution
+    }
+}
+
+impl RandomIntraMove {
+    pub fn new() -> Self {
+        RandomIntraMove {}
+    }
+}
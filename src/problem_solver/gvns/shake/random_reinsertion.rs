@@ -0,0 +1,59 @@
+use super::*;
+
+/// A shaking operator that removes a number of random tasks equal to the strength
+/// and reinserts each one of them in a random position of a different machine
+#[derive(Default)]
+pub struct RandomReinsertion {}
+
+impl Shake for RandomReinsertion {
+    fn shake(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+        strength: usize,
+    ) -> ProblemSolution {
+        if solution.task_assignment_matrix.len() < 2 {
+            return solution;
+        }
+        let mut removed_tasks = Vec::new();
+        for _ in 0..strength {
+            let mut possible_tasks: Vec<(usize, usize)> =
+                (0..solution.task_assignment_matrix.len())
+                    .filter(|&machine| !solution.task_assignment_matrix[machine].is_empty())
+                    .flat_map(|from_machine| {
+                        (0..solution.task_assignment_matrix[from_machine].len())
+                            .map(move |from_pos| (from_machine, from_pos))
+                    })
+                    .collect();
+            if possible_tasks.is_empty() {
+                break;
+            }
+            let (from_machine, from_pos): (usize, usize) =
+                possible_tasks.remove(rand::random::<usize>() % possible_tasks.len());
+            let task = solution.task_assignment_matrix[from_machine].remove(from_pos);
+            removed_tasks.push((from_machine, task));
+        }
+        for (from_machine, task) in removed_tasks {
+            let to_machine = loop {
+                let number = rand::random::<usize>() % solution.task_assignment_matrix.len();
+                if number != from_machine {
+                    break number;
+                }
+            };
+            let to_pos =
+                rand::random::<usize>() % (solution.task_assignment_matrix[to_machine].len() + 1);
+            solution.task_assignment_matrix[to_machine].insert(to_pos, task);
+            solution.tcts_by_machine[from_machine] = instance
+                .calculate_total_completion_time(&solution.task_assignment_matrix[from_machine]);
+            solution.tcts_by_machine[to_machine] = instance
+                .calculate_total_completion_time(&solution.task_assignment_matrix[to_machine]);
+        }
+        solution
+    }
+}
+
+impl RandomReinsertion {
+    pub fn new() -> Self {
+        RandomReinsertion {}
+    }
+}
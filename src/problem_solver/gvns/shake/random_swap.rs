@@ -0,0 +1,46 @@
+use super::*;
+
+/// A shaking operator that performs a number of random swaps between tasks of
+/// different machines equal to the strength
+#[derive(Default)]
+pub struct RandomSwap {}
+
+impl Shake for RandomSwap {
+    fn shake(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+        strength: usize,
+    ) -> ProblemSolution {
+        let mut machines: Vec<usize> = (0..solution.task_assignment_matrix.len())
+            .filter(|&machine| !solution.task_assignment_matrix[machine].is_empty())
+            .collect();
+        if machines.len() < 2 {
+            return solution;
+        }
+        for _ in 0..strength {
+            let first_machine = machines.swap_remove(rand::random::<usize>() % machines.len());
+            let second_machine = machines[rand::random::<usize>() % machines.len()];
+            machines.push(first_machine);
+            let first_index =
+                rand::random::<usize>() % solution.task_assignment_matrix[first_machine].len();
+            let second_index =
+                rand::random::<usize>() % solution.task_assignment_matrix[second_machine].len();
+            let first_task = solution.task_assignment_matrix[first_machine][first_index];
+            solution.task_assignment_matrix[first_machine][first_index] =
+                solution.task_assignment_matrix[second_machine][second_index];
+            solution.task_assignment_matrix[second_machine][second_index] = first_task;
+            for &machine in &[first_machine, second_machine] {
+                solution.tcts_by_machine[machine] = instance
+                    .calculate_total_completion_time(&solution.task_assignment_matrix[machine]);
+            }
+        }
+        solution
+    }
+}
+
+impl RandomSwap {
+    pub fn new() -> Self {
+        RandomSwap {}
+    }
+}
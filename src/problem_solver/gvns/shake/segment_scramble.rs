@@ -0,0 +1,38 @@
+use super::*;
+use rand::seq::SliceRandom;
+
+/// A shaking operator that chooses a random segment of consecutive tasks in a random
+/// machine and shuffles it. The length of the segment is the strength plus one, or the
+/// number of tasks in the machine if it has less tasks
+#[derive(Default)]
+pub struct SegmentScramble {}
+
+impl Shake for SegmentScramble {
+    fn shake(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+        strength: usize,
+    ) -> ProblemSolution {
+        let machines: Vec<usize> = (0..solution.task_assignment_matrix.len())
+            .filter(|&machine| solution.task_assignment_matrix[machine].len() > 1)
+            .collect();
+        if machines.is_empty() {
+            return solution;
+        }
+        let machine = machines[rand::random::<usize>() % machines.len()];
+        let tasks = &mut solution.task_assignment_matrix[machine];
+        let length = (strength + 1).min(tasks.len());
+        let start = rand::random::<usize>() % (tasks.len() - length + 1);
+        tasks[start..start + length].shuffle(&mut rand::thread_rng());
+        solution.tcts_by_machine[machine] =
+            instance.calculate_total_completion_time(&solution.task_assignment_matrix[machine]);
+        solution
+    }
+}
+
+impl SegmentScramble {
+    pub fn new() -> Self {
+        SegmentScramble {}
+    }
+}
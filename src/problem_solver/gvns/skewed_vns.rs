@@ -0,0 +1,78 @@
+use super::*;
+
+/// A skewed VNS implementation. It works like the [BasicVNS](BasicVNS) but it can move
+/// to solutions slightly worse than the actual one if they are far from it, so it can
+/// explore valleys far from the actual solution. A solution is accepted when its TCT minus
+/// alpha times its [distance](ProblemSolution::distance) to the actual solution is lower
+/// than the TCT of the actual solution. The best solution found is always kept and the
+/// k only goes back to 1 when it improves, so every search ends
+pub struct SkewedVNS<L: LocalSearch, S: StopCriterion> {
+    max_k: usize,
+    stop_criterion: S,
+    shakes: Vec<Box<dyn Shake>>,
+    local_search: L,
+    alpha: f64,
+    initial_solution: InitialSolution,
+}
+
+impl<L: LocalSearch, S: StopCriterion> ProblemSolver for SkewedVNS<L, S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let SkewedVNS {
+            max_k,
+            stop_criterion,
+            shakes,
+            local_search,
+            alpha,
+            initial_solution,
+        } = self;
+        restart_loop(stop_criterion, |best_solution| {
+            let mut solution = initial_solution.get(instance, best_solution);
+            let mut best_solution = solution.clone();
+            let mut k = 1;
+            while k <= *max_k {
+                let mut new_solution = shake_environment(shakes, instance, solution.clone(), k);
+                new_solution = local_search.improve(instance, new_solution);
+                let new_solution_tct = new_solution.get_total_completion_time();
+                let skewed_tct =
+                    new_solution_tct as f64 - *alpha * solution.distance(&new_solution) as f64;
+                if new_solution_tct < best_solution.get_total_completion_time() {
+                    best_solution = new_solution.clone();
+                    solution = new_solution;
+                    k = 1;
+                } else {
+                    if skewed_tct < solution.get_total_completion_time() as f64 {
+                        solution = new_solution;
+                    }
+                    k += 1;
+                }
+            }
+            best_solution
+        })
+    }
+}
+
+impl<L: LocalSearch, S: StopCriterion> SkewedVNS<L, S> {
+    /// Creates a new instance with the specified max k, stop criterion, shaking operators,
+    /// local search, alpha and initial solution. The max k must be greater than 0, there
+    /// must be at least one shaking operator and alpha can't be negative
+    pub fn new(
+        max_k: usize,
+        stop_criterion: S,
+        shakes: Vec<Box<dyn Shake>>,
+        local_search: L,
+        alpha: f64,
+        initial_solution: InitialSolution,
+    ) -> Self {
+        assert!(max_k > 0);
+        assert!(!shakes.is_empty());
+        assert!(alpha >= 0.0);
+        SkewedVNS {
+            max_k,
+            stop_criterion,
+            shakes,
+            local_search,
+            alpha,
+            initial_solution,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use super::{
+    grasp::{local_search::LocalSearch, stop_criterion::StopCriterion},
+    gvns::shake::Shake,
+    ProblemInstance, ProblemSolution, ProblemSolver,
+};
+
+/// An iterated local search implementation. It builds a solution with a constructive
+/// solver and improves it with a local search. Then, until the stop criterion is fullfilled,
+/// it perturbs the actual solution with a [shaking operator](super::gvns::shake::Shake),
+/// improves it with the local search and decides with the acceptance criterion if the
+/// search continues from the new local minimum. Unlike the [GVNS](super::GVNS) the
+/// strength of the perturbation doesn't follow a k ladder, and unlike the
+/// [GRASP](super::GRASP) it doesn't start from scratch in each iteration
+pub struct ILS<L: LocalSearch, S: StopCriterion> {
+    constructive_solver: Box<dyn ProblemSolver>,
+    local_search: L,
+    perturbation: Box<dyn Shake>,
+    perturbation_strength: PerturbationStrength,
+    acceptance_criterion: AcceptanceCriterion,
+    stop_criterion: S,
+}
+
+/// The strength with which the [ILS](ILS) perturbs the solutions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PerturbationStrength {
+    /// Always uses the same strength
+    Fixed(usize),
+    /// Starts with the min strength and increases it by one each iteration that doesn't
+    /// improve the best solution, until the max one. It goes back to the min strength
+    /// when the best solution improves
+    Adaptive { min: usize, max: usize },
+}
+
+/// The criterion that decides if the [ILS](ILS) continues from the new local minimum
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AcceptanceCriterion {
+    /// Only accepts the new local minimum if it's better than the actual solution
+    Better,
+    /// Always accepts the new local minimum
+    RandomWalk,
+    /// Works like the Better criterion, but after the specified number of iterations
+    /// without improving the best solution it restarts from a new constructive solution
+    RestartAfterStagnation(usize),
+}
+
+impl<L: LocalSearch, S: StopCriterion> ProblemSolver for ILS<L, S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut solution = self
+            .local_search
+            .improve(instance, self.constructive_solver.solve(instance));
+        let mut best_solution = solution.clone();
+        let mut best_solution_tct = best_solution.get_total_completion_time();
+        let mut strength = self.perturbation_strength.initial();
+        let mut iterations_without_improvement = 0;
+        loop {
+            let new_solution = self.local_search.improve(
+                instance,
+                self.perturbation
+                    .shake(instance, solution.clone(), strength),
+            );
+            let new_solution_tct = new_solution.get_total_completion_time();
+            if self
+                .stop_criterion
+                .stop(best_solution_tct, new_solution_tct)
+            {
+                if best_solution_tct <= new_solution_tct {
+                    return best_solution;
+                } else {
+                    return new_solution;
+                }
+            }
+            if new_solution_tct < best_solution_tct {
+                best_solution = new_solution.clone();
+                best_solution_tct = new_solution_tct;
+                strength = self.perturbation_strength.initial();
+                iterations_without_improvement = 0;
+            } else {
+                strength = self.perturbation_strength.next(strength);
+                iterations_without_improvement += 1;
+            }
+            solution = match self.acceptance_criterion {
+                AcceptanceCriterion::RandomWalk => new_solution,
+                AcceptanceCriterion::RestartAfterStagnation(max_iterations)
+                    if iterations_without_improvement >= max_iterations =>
+                {
+                    iterations_without_improvement = 0;
+                    strength = self.perturbation_strength.initial();
+                    self.local_search
+                        .improve(instance, self.constructive_solver.solve(instance))
+                }
+                _ if new_solution_tct < solution.get_total_completion_time() => new_solution,
+                _ => solution,
+            };
+        }
+    }
+}
+
+impl<L: LocalSearch, S: StopCriterion> ILS<L, S> {
+    /// Creates a new instance with the specified arguments. The constructive solver
+    /// builds the first solution and the ones used in the restarts
+    pub fn new(
+        constructive_solver: Box<dyn ProblemSolver>,
+        local_search: L,
+        perturbation: Box<dyn Shake>,
+        perturbation_strength: PerturbationStrength,
+        acceptance_criterion: AcceptanceCriterion,
+        stop_criterion: S,
+    ) -> Self {
+        match perturbation_strength {
+            PerturbationStrength::Fixed(strength) => assert!(strength > 0),
+            PerturbationStrength::Adaptive { min, max } => assert!(min > 0 && min <= max),
+        }
+        ILS {
+            constructive_solver,
+            local_search,
+            perturbation,
+            perturbation_strength,
+            acceptance_criterion,
+            stop_criterion,
+        }
+    }
+}
+
+impl PerturbationStrength {
+    fn initial(&self) -> usize {
+        match *self {
+            PerturbationStrength::Fixed(strength) => strength,
+            PerturbationStrength::Adaptive { min, .. } => min,
+        }
+    }
+
+    fn next(&self, strength: usize) -> usize {
+        match *self {
+            PerturbationStrength::Fixed(strength) => strength,
+            PerturbationStrength::Adaptive { max, .. } => (strength + 1).min(max),
+        }
+    }
+}
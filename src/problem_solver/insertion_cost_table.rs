@@ -0,0 +1,171 @@
+use super::{GreedySolver, NewTask, ProblemInstance, ProblemSolution};
+use std::collections::HashSet;
+
+/// A cache of the best insertion of each unassigned task in each machine, so a greedy
+/// construction only has to evaluate again the insertions in the machine that changed.
+///
+/// The insertions are evaluated in O(1) with the prefix sums of the contributions of the
+/// tasks of each machine, being the contribution of a task its processing time plus the
+/// setup time from its predecessor. If a machine has L tasks, inserting the task x in the
+/// position q, between pred and succ, increments the TCT by:
+///
+/// prefix\[q\] + (L + 1 - q) · (p_x + s_pred,x) + (L - q) · (s_x,succ - s_pred,succ)
+///
+/// The first term is there because the tasks before the new one are counted once more in
+/// the completion times of the tasks after them. The increment can be negative if the setup
+/// times don't satisfy the triangle inequality
+pub(super) struct InsertionCostTable {
+    // The prefix sums of the contributions of the tasks of each machine
+    prefix_contributions: Vec<Vec<usize>>,
+    // The best position and TCT increment of each task in each machine, None if the task
+    // has already been assigned
+    best_insertions: Vec<Vec<Option<(usize, isize)>>>,
+}
+
+impl InsertionCostTable {
+    /// Creates the table of a partial solution
+    pub(super) fn new(
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+        asigned_tasks: &HashSet<usize>,
+    ) -> Self {
+        let number_of_machines = solution.task_assignment_matrix.len();
+        let mut table = InsertionCostTable {
+            prefix_contributions: vec![Vec::new(); number_of_machines],
+            best_insertions: vec![vec![None; number_of_machines]; instance.task_times().len()],
+        };
+        for machine in 0..number_of_machines {
+            table.update_machine(instance, solution, asigned_tasks, machine);
+        }
+        table
+    }
+
+    /// Returns the insertion with the lowest TCT increment, if there is any task left
+    pub(super) fn get_best_new_task(&self) -> Option<NewTask> {
+        (0..self.prefix_contributions.len())
+            .flat_map(|machine| {
+                self.best_insertions
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(task, insertions)| {
+                        insertions[machine].map(|(position, tct_increment)| NewTask {
+                            machine,
+                            task,
+                            position,
+                            tct_increment,
+                        })
+                    })
+            })
+            .min_by_key(|new_task| new_task.tct_increment)
+    }
+
+    /// Adds the new task to the solution and updates the insertions of its machine
+    pub(super) fn insert_task(
+        &mut self,
+        instance: &ProblemInstance,
+        solution: &mut ProblemSolution,
+        asigned_tasks: &mut HashSet<usize>,
+        new_task: &NewTask,
+    ) {
+        GreedySolver::insert_task(solution, asigned_tasks, new_task);
+        self.best_insertions[new_task.task] = vec![None; self.prefix_contributions.len()];
+        self.update_machine(instance, solution, asigned_tasks, new_task.machine);
+    }
+
+    /// Returns the TCT increment of inserting the task in the position of the task list,
+    /// whose prefix sums of contributions are the specified ones
+    fn insertion_delta(
+        instance: &ProblemInstance,
+        task_list: &[usize],
+        prefix_contributions: &[usize],
+        task: usize,
+        position: usize,
+    ) -> isize {
+        let setup_times = instance.setup_times();
+        let length = task_list.len() as isize;
+        let position_weight = length - position as isize;
+        // The tasks are indexed like in the setup times matrix
+        let predecessor = if position == 0 {
+            0
+        } else {
+            task_list[position - 1] + 1
+        };
+        let mut delta = prefix_contributions[position] as isize
+            + (position_weight + 1)
+                * (instance.task_times()[task] + setup_times[predecessor][task + 1]) as isize;
+        if let Some(&successor) = task_list.get(position) {
+            delta += position_weight
+                * (setup_times[task + 1][successor + 1] as isize
+                    - setup_times[predecessor][successor + 1] as isize);
+        }
+        delta
+    }
+
+    fn update_machine(
+        &mut self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+        asigned_tasks: &HashSet<usize>,
+        machine: usize,
+    ) {
+        let task_list = &solution.task_assignment_matrix[machine];
+        let mut prefix_contributions = Vec::with_capacity(task_list.len() + 1);
+        prefix_contributions.push(0);
+        let mut predecessor = 0;
+        for &task in task_list {
+            let contribution =
+                instance.task_times()[task] + instance.setup_times()[predecessor][task + 1];
+            prefix_contributions.push(prefix_contributions.last().unwrap() + contribution);
+            predecessor = task + 1;
+        }
+        for (task, insertions) in self.best_insertions.iter_mut().enumerate() {
+            if asigned_tasks.contains(&task) {
+                continue;
+            }
+            insertions[machine] = (0..=task_list.len())
+                .map(|position| {
+                    let delta = InsertionCostTable::insertion_delta(
+                        instance,
+                        task_list,
+                        &prefix_contributions,
+                        task,
+                        position,
+                    );
+                    (position, delta)
+                })
+                .min_by_key(|&(_, delta)| delta);
+        }
+        self.prefix_contributions[machine] = prefix_contributions;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insertion_delta_matches_total_completion_time() {
+        let instance = ProblemInstance::from_file("problem_instances/I40j_2m_S1_1.txt").unwrap();
+        let task_list = vec![4, 17, 0, 31, 9];
+        let solution =
+            ProblemSolution::new_unchecked(&instance, vec![task_list.clone(), Vec::new()]);
+        let asigned_tasks = task_list.iter().copied().collect();
+        let table = InsertionCostTable::new(&instance, &solution, &asigned_tasks);
+        for task in (0..instance.task_times().len()).filter(|task| !task_list.contains(task)) {
+            for position in 0..=task_list.len() {
+                let mut new_task_list = task_list.clone();
+                new_task_list.insert(position, task);
+                let expected = instance.calculate_total_completion_time(&new_task_list) as isize
+                    - solution.get_tcts_by_machine()[0] as isize;
+                let delta = InsertionCostTable::insertion_delta(
+                    &instance,
+                    &task_list,
+                    &table.prefix_contributions[0],
+                    task,
+                    position,
+                );
+                assert_eq!(delta, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use super::{
+    grasp::{local_search::LocalSearch, stop_criterion::StopCriterion},
+    GreedySolver, ProblemInstance, ProblemSolution, ProblemSolver,
+};
+use std::collections::HashSet;
+
+/// An iterated greedy implementation. It builds a solution with the
+/// [GreedySolver](super::GreedySolver) and then, until the stop criterion is fullfilled,
+/// it removes some random tasks from the actual solution (destruction), reinserts
+/// them with the best insertion logic of the greedy solver (construction) and improves
+/// the result with a local search. The new solution is accepted if it's better than
+/// the actual one, or with a probability that depends on a constant temperature like in
+/// the algorithm by Ruiz and Stützle. The temperature is the temperature factor times
+/// the mean of the processing time plus the mean setup time of the tasks, divided by 10
+pub struct IteratedGreedy<L: LocalSearch, S: StopCriterion> {
+    tasks_to_remove: usize,
+    temperature_factor: f64,
+    local_search: L,
+    stop_criterion: S,
+}
+
+impl<L: LocalSearch, S: StopCriterion> ProblemSolver for IteratedGreedy<L, S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let temperature = self.get_temperature(instance);
+        let mut solution = self
+            .local_search
+            .improve(instance, GreedySolver::new().solve(instance));
+        let mut solution_tct = solution.get_total_completion_time();
+        let mut best_solution = solution.clone();
+        let mut best_solution_tct = solution_tct;
+        loop {
+            let new_solution = self.destroy(instance, solution.clone());
+            let new_solution = self.local_search.improve(
+                instance,
+                IteratedGreedy::<L, S>::construct(instance, new_solution),
+            );
+            let new_solution_tct = new_solution.get_total_completion_time();
+            if self
+                .stop_criterion
+                .stop(best_solution_tct, new_solution_tct)
+            {
+                if best_solution_tct <= new_solution_tct {
+                    return best_solution;
+                } else {
+                    return new_solution;
+                }
+            }
+            if new_solution_tct < solution_tct {
+                if new_solution_tct < best_solution_tct {
+                    best_solution = new_solution.clone();
+                    best_solution_tct = new_solution_tct;
+                }
+                solution = new_solution;
+                solution_tct = new_solution_tct;
+            } else if rand::random::<f64>()
+                < (-((new_solution_tct - solution_tct) as f64) / temperature).exp()
+            {
+                solution = new_solution;
+                solution_tct = new_solution_tct;
+            }
+        }
+    }
+}
+
+impl<L: LocalSearch, S: StopCriterion> IteratedGreedy<L, S> {
+    /// Creates a new instance that will remove the specified number of tasks in each
+    /// iteration. The number of tasks to remove must be greater than 0 and the
+    /// temperature factor can't be negative
+    pub fn new(
+        tasks_to_remove: usize,
+        temperature_factor: f64,
+        local_search: L,
+        stop_criterion: S,
+    ) -> Self {
+        assert!(tasks_to_remove > 0);
+        assert!(temperature_factor >= 0.0);
+        IteratedGreedy {
+            tasks_to_remove,
+            temperature_factor,
+            local_search,
+            stop_criterion,
+        }
+    }
+
+    fn get_temperature(&self, instance: &ProblemInstance) -> f64 {
+        let number_of_tasks = instance.task_times().len();
+        let total_time: f64 = (0..number_of_tasks)
+            .map(|task| {
+                let setup_time: usize = (0..=number_of_tasks)
+                    .filter(|&predecessor| predecessor != task + 1)
+                    .map(|predecessor| instance.setup_times()[predecessor][task + 1])
+                    .sum();
+                instance.task_times()[task] as f64 + setup_time as f64 / number_of_tasks as f64
+            })
+            .sum();
+        self.temperature_factor * total_time / (10 * number_of_tasks) as f64
+    }
+
+    fn destroy(
+        &self,
+        instance: &ProblemInstance,
+        mut solution: ProblemSolution,
+    ) -> ProblemSolution {
+        for _ in 0..self.tasks_to_remove {
+            let machines: Vec<usize> = (0..solution.task_assignment_matrix.len())
+                .filter(|&machine| !solution.task_assignment_matrix[machine].is_empty())
+                .collect();
+            if machines.is_empty() {
+                break;
+            }
+            let machine = machines[rand::random::<usize>() % machines.len()];
+            let tasks = &mut solution.task_assignment_matrix[machine];
+            tasks.remove(rand::random::<usize>() % tasks.len());
+            solution.tcts_by_machine[machine] =
+                instance.calculate_total_completion_time(&solution.task_assignment_matrix[machine]);
+        }
+        solution
+    }
+
+    fn construct(instance: &ProblemInstance, mut solution: ProblemSolution) -> ProblemSolution {
+        let mut asigned_tasks: HashSet<usize> = solution
+            .task_assignment_matrix
+            .iter()
+            .flatten()
+            .copied()
+            .collect();
+        GreedySolver::complete(&mut solution, instance, &mut asigned_tasks);
+        solution
+    }
+}
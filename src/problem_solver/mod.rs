@@ -1,18 +1,46 @@
 use super::ProblemInstance;
 use std::cmp::Ordering;
 
+mod aco;
+mod beam_search_solver;
+mod cluster_first_solver;
+mod dispatching_rule_solver;
 mod fast_greedy_solver;
+pub mod genetic_algorithm;
 pub mod grasp;
 mod greedy_solver;
 pub mod gvns;
+mod ils;
+mod insertion_cost_table;
+mod iterated_greedy;
+mod pilot_solver;
 mod problem_solution;
+mod problem_solution_error;
 mod randomized_greedy_solver;
+mod regret_insertion_solver;
+mod seeding;
+mod simulated_annealing;
+mod tabu_search;
+pub use aco::ACO;
+pub use beam_search_solver::BeamSearchSolver;
+pub use cluster_first_solver::{ClusterFirstSolver, Sequencing};
+pub use dispatching_rule_solver::{DispatchingRule, DispatchingRuleSolver};
 pub use fast_greedy_solver::FastGreedySolver;
-pub use grasp::GRASP;
+pub use genetic_algorithm::{GeneticAlgorithm, Memetic};
+pub use grasp::{ReactiveGRASP, GRASP};
 pub use greedy_solver::GreedySolver;
-pub use gvns::GVNS;
+pub use gvns::{BasicVNS, InitialSolution, ReducedVNS, SkewedVNS, VndVariant, GVNS};
+pub use ils::{AcceptanceCriterion, PerturbationStrength, ILS};
+use insertion_cost_table::InsertionCostTable;
+pub use iterated_greedy::IteratedGreedy;
+pub use pilot_solver::PilotSolver;
 pub use problem_solution::ProblemSolution;
-pub use randomized_greedy_solver::RandomizedGreedySolver;
+pub use problem_solution_error::ProblemSolutionError;
+pub use randomized_greedy_solver::{RandomizedGreedySolver, RclBias, RclPolicy};
+pub use regret_insertion_solver::RegretInsertionSolver;
+pub use seeding::Seeding;
+pub use simulated_annealing::{CoolingSchedule, InitialTemperature, SimulatedAnnealing};
+pub use tabu_search::{Diversification, TabuSearch};
 
 /// A trait for an algorithm that is able to solve an instance of the problem
 pub trait ProblemSolver {
@@ -25,7 +53,7 @@ struct NewTask {
     machine: usize,
     task: usize,
     position: usize,
-    tct_increment: usize,
+    tct_increment: isize,
 }
 
 impl Ord for NewTask {
@@ -0,0 +1,87 @@
+use super::{
+    FastGreedySolver, GreedySolver, NewTask, ProblemInstance, ProblemSolution, ProblemSolver,
+    Seeding,
+};
+use std::collections::HashSet;
+
+/// A pilot method that creates a solution for the problem adding a task in each step, like
+/// the [GreedySolver](super::GreedySolver). Instead of taking the insertion with the lowest
+/// TCT increment, it takes the best candidate insertions and rates each one by adding
+/// more tasks with the [FastGreedySolver](super::FastGreedySolver) (the pilot) and measuring
+/// the TCT of the result. The lookahead depth limits the number of tasks the pilot adds,
+/// if there isn't a limit it completes the schedule. The first task of each machine is
+/// chosen with the [seeding](super::Seeding)
+pub struct PilotSolver {
+    number_of_candidates: usize,
+    depth: Option<usize>,
+    seeding: Seeding,
+}
+
+impl ProblemSolver for PilotSolver {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut asigned_tasks = HashSet::with_capacity(instance.task_times().len());
+        let mut solution = ProblemSolution {
+            tcts_by_machine: Vec::with_capacity(instance.number_of_machines()),
+            task_assignment_matrix: Vec::with_capacity(instance.number_of_machines()),
+        };
+        self.seeding
+            .seed(instance, &mut solution, &mut asigned_tasks);
+        while asigned_tasks.len() < instance.task_times().len() {
+            let new_task = GreedySolver::get_best_new_tasks(
+                &solution,
+                instance,
+                &asigned_tasks,
+                self.number_of_candidates,
+            )
+            .into_iter()
+            .min_by_key(|new_task| self.pilot(instance, &solution, &asigned_tasks, new_task))
+            .unwrap();
+            GreedySolver::insert_task(&mut solution, &mut asigned_tasks, &new_task);
+        }
+        solution
+    }
+}
+
+impl PilotSolver {
+    /// Creates a new solver that rates the specified number of candidates in each step,
+    /// that must be greater than 0. If the depth is None the pilot completes the schedule
+    pub fn new(number_of_candidates: usize, depth: Option<usize>) -> Self {
+        PilotSolver::with_seeding(number_of_candidates, depth, Seeding::CheapestFirst)
+    }
+
+    /// Creates a new solver that rates the specified number of candidates in each step
+    /// and chooses the first task of each machine with the seeding
+    pub fn with_seeding(
+        number_of_candidates: usize,
+        depth: Option<usize>,
+        seeding: Seeding,
+    ) -> Self {
+        assert!(number_of_candidates > 0);
+        PilotSolver {
+            number_of_candidates,
+            depth,
+            seeding,
+        }
+    }
+
+    /// Returns the TCT obtained by adding the new task and running the pilot
+    fn pilot(
+        &self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+        asigned_tasks: &HashSet<usize>,
+        new_task: &NewTask,
+    ) -> usize {
+        let mut solution = solution.clone();
+        let mut asigned_tasks = asigned_tasks.clone();
+        GreedySolver::insert_task(&mut solution, &mut asigned_tasks, new_task);
+        let remaining_tasks = instance.task_times().len() - asigned_tasks.len();
+        for _ in 0..self
+            .depth
+            .map_or(remaining_tasks, |depth| depth.min(remaining_tasks))
+        {
+            FastGreedySolver::add_task(&mut solution, instance, &mut asigned_tasks);
+        }
+        solution.get_total_completion_time()
+    }
+}
@@ -1,3 +1,5 @@
+use super::{ProblemInstance, ProblemSolutionError};
+
 /// A struct that represents a solution to the problem. It contains a vector
 /// with the total completion time of each machine and a matrix with the tasks
 /// assigned to each machine
@@ -8,6 +10,52 @@ pub struct ProblemSolution {
 }
 
 impl ProblemSolution {
+    /// Creates a solution from the tasks assigned to each machine, calculating the total
+    /// completion time of each machine with the times of the instance. It allows to use
+    /// schedules built outside of this crate, so it checks that there is a list of tasks
+    /// for each machine of the instance and that every task of the instance is assigned
+    /// exactly once
+    pub fn new(
+        instance: &ProblemInstance,
+        task_assignment_matrix: Vec<Vec<usize>>,
+    ) -> Result<Self, ProblemSolutionError> {
+        if task_assignment_matrix.len() != instance.number_of_machines() {
+            return Err(ProblemSolutionError::WrongNumberOfMachines(
+                task_assignment_matrix.len(),
+            ));
+        }
+        let mut assigned = vec![false; instance.task_times().len()];
+        for &task in task_assignment_matrix.iter().flatten() {
+            match assigned.get_mut(task) {
+                None => return Err(ProblemSolutionError::UnknownTask(task)),
+                Some(true) => return Err(ProblemSolutionError::RepeatedTask(task)),
+                Some(assigned) => *assigned = true,
+            }
+        }
+        if let Some(task) = assigned.iter().position(|&assigned| !assigned) {
+            return Err(ProblemSolutionError::MissingTask(task));
+        }
+        Ok(ProblemSolution::new_unchecked(
+            instance,
+            task_assignment_matrix,
+        ))
+    }
+
+    /// Creates a solution like [new](ProblemSolution::new) but without any check, for the
+    /// solvers that already build valid schedules, or partial ones
+    pub(super) fn new_unchecked(
+        instance: &ProblemInstance,
+        task_assignment_matrix: Vec<Vec<usize>>,
+    ) -> Self {
+        ProblemSolution {
+            tcts_by_machine: task_assignment_matrix
+                .iter()
+                .map(|tasks| instance.calculate_total_completion_time(tasks))
+                .collect(),
+            task_assignment_matrix,
+        }
+    }
+
     /// Allows getting the total completion time of the solution
     pub fn get_total_completion_time(&self) -> usize {
         self.tcts_by_machine.iter().sum()
@@ -22,4 +70,103 @@ impl ProblemSolution {
     pub fn get_tasks_by_machine(&self) -> &Vec<Vec<usize>> {
         &self.task_assignment_matrix
     }
+
+    /// Calculates the distance between two solutions. It is the number of tasks whose
+    /// previous task, or the inactive state if it's the first one of its machine, isn't
+    /// the same in both solutions. It doesn't depend on the order of the machines
+    pub fn distance(&self, other: &ProblemSolution) -> usize {
+        let number_of_tasks = self
+            .task_assignment_matrix
+            .iter()
+            .chain(other.task_assignment_matrix.iter())
+            .flatten()
+            .max()
+            .map_or(0, |&task| task + 1);
+        let predecessors = self.get_predecessors(number_of_tasks);
+        other
+            .get_predecessors(number_of_tasks)
+            .iter()
+            .zip(predecessors.iter())
+            .filter(|(other_predecessor, predecessor)| other_predecessor != predecessor)
+            .count()
+    }
+
+    /// Returns the predecessor of each task in the setup times notation, 0 being the
+    /// inactive state. The tasks that aren't in the solution don't have one
+    fn get_predecessors(&self, number_of_tasks: usize) -> Vec<Option<usize>> {
+        let mut predecessors = vec![None; number_of_tasks];
+        for tasks in &self.task_assignment_matrix {
+            for (index, &task) in tasks.iter().enumerate() {
+                predecessors[task] = Some(if index == 0 { 0 } else { tasks[index - 1] + 1 });
+            }
+        }
+        predecessors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solution(task_assignment_matrix: Vec<Vec<usize>>) -> ProblemSolution {
+        ProblemSolution {
+            tcts_by_machine: vec![0; task_assignment_matrix.len()],
+            task_assignment_matrix,
+        }
+    }
+
+    #[test]
+    fn new_accepts_permutation() {
+        let instance = ProblemInstance::from_file("problem_instances/I40j_2m_S1_1.txt").unwrap();
+        let solution =
+            ProblemSolution::new(&instance, vec![(0..40).rev().collect(), Vec::new()]).unwrap();
+        assert_eq!(
+            solution.get_tcts_by_machine()[0],
+            instance.calculate_total_completion_time(&(0..40).rev().collect())
+        );
+    }
+
+    #[test]
+    fn new_rejects_invalid_schedules() {
+        let instance = ProblemInstance::from_file("problem_instances/I40j_2m_S1_1.txt").unwrap();
+        let check = |task_assignment_matrix: Vec<Vec<usize>>| {
+            ProblemSolution::new(&instance, task_assignment_matrix).err()
+        };
+        assert_eq!(
+            check(vec![(0..40).collect()]),
+            Some(ProblemSolutionError::WrongNumberOfMachines(1))
+        );
+        assert_eq!(
+            check(vec![(0..40).collect(), vec![40]]),
+            Some(ProblemSolutionError::UnknownTask(40))
+        );
+        assert_eq!(
+            check(vec![(0..40).collect(), vec![7]]),
+            Some(ProblemSolutionError::RepeatedTask(7))
+        );
+        assert_eq!(
+            check(vec![(0..20).collect(), (21..40).collect()]),
+            Some(ProblemSolutionError::MissingTask(20))
+        );
+    }
+
+    #[test]
+    fn distance_same_solution() {
+        let solution = solution(vec![vec![0, 3, 1], vec![2, 4]]);
+        assert_eq!(solution.distance(&solution.clone()), 0);
+    }
+
+    #[test]
+    fn distance_ignores_machine_order() {
+        let first = solution(vec![vec![0, 3, 1], vec![2, 4]]);
+        let second = solution(vec![vec![2, 4], vec![0, 3, 1]]);
+        assert_eq!(first.distance(&second), 0);
+    }
+
+    #[test]
+    fn distance_counts_changed_predecessors() {
+        let first = solution(vec![vec![0, 3, 1], vec![2, 4]]);
+        let second = solution(vec![vec![0, 1], vec![2, 3, 4]]);
+        assert_eq!(first.distance(&second), 3);
+    }
 }
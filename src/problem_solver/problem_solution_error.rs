@@ -0,0 +1,36 @@
+//! This file defines the different errors that can appear while
+//! creating a solution from the tasks assigned to each machine
+use std::error::Error;
+use std::fmt;
+
+/// The enum that encapsulates the errors that can appear while creating a solution from
+/// the tasks assigned to each machine. The number of machines can be wrong, or a task can
+/// be unknown, repeated or missing
+#[derive(Debug, PartialEq)]
+pub enum ProblemSolutionError {
+    WrongNumberOfMachines(usize),
+    UnknownTask(usize),
+    RepeatedTask(usize),
+    MissingTask(usize),
+}
+
+impl fmt::Display for ProblemSolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProblemSolutionError::WrongNumberOfMachines(machines) => {
+                write!(f, "The solution has {} machines", machines)
+            }
+            ProblemSolutionError::UnknownTask(task) => {
+                write!(f, "The task {} isn't part of the instance", task)
+            }
+            ProblemSolutionError::RepeatedTask(task) => {
+                write!(f, "The task {} is assigned more than once", task)
+            }
+            ProblemSolutionError::MissingTask(task) => {
+                write!(f, "The task {} isn't assigned to any machine", task)
+            }
+        }
+    }
+}
+
+impl Error for ProblemSolutionError {}
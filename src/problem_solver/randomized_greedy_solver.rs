@@ -1,11 +1,43 @@
-use super::{NewTask, ProblemInstance, ProblemSolution, ProblemSolver};
-use std::collections::{BinaryHeap, HashSet};
+use super::{GreedySolver, NewTask, ProblemInstance, ProblemSolution, ProblemSolver, Seeding};
+use std::collections::HashSet;
 
 /// A greedy algorithm that creates a solution for the problem by evaluating
 /// in each step the best position and machine to add a determined task to the
-/// solution and taking the k bests possible insertions. It then chooses randomly between those
+/// solution and taking the k bests possible insertions. It then chooses randomly between those.
+/// Other ways to build the restricted candidate list and to choose from it can be
+/// specified with an [RCL policy](RclPolicy) and a [bias](RclBias). The first task of each
+/// machine is chosen with the [seeding](super::Seeding)
 pub struct RandomizedGreedySolver {
-    size_to_choose_from: usize,
+    rcl_policy: RclPolicy,
+    bias: RclBias,
+    seeding: Seeding,
+}
+
+/// The way in which the [RandomizedGreedySolver](RandomizedGreedySolver) builds the
+/// restricted candidate list
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RclPolicy {
+    /// Takes the k insertions with the lowest TCT increment
+    Cardinality(usize),
+    /// Takes all the insertions whose TCT increment is at most min + alpha · (max - min),
+    /// being alpha between 0 (pure greedy) and 1 (pure random)
+    Value(f64),
+}
+
+/// The bias with which the [RandomizedGreedySolver](RandomizedGreedySolver) chooses from
+/// the restricted candidate list, as proposed by Bresina. Each candidate is chosen with a
+/// probability proportional to a weight that depends on its rank r, starting at 1 for the
+/// insertion with the lowest TCT increment
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RclBias {
+    /// All the candidates have the same weight
+    Uniform,
+    /// The weight is 1 / r
+    Linear,
+    /// The weight is e^(-r)
+    Exponential,
+    /// The weight is 1 / ln(r + 1)
+    Logarithmic,
 }
 
 impl ProblemSolver for RandomizedGreedySolver {
@@ -15,7 +47,8 @@ impl ProblemSolver for RandomizedGreedySolver {
             tcts_by_machine: Vec::with_capacity(instance.number_of_machines()),
             task_assignment_matrix: Vec::with_capacity(instance.number_of_machines()),
         };
-        RandomizedGreedySolver::choose_initial_tasks(&mut solution, instance, &mut asigned_tasks);
+        self.seeding
+            .seed(instance, &mut solution, &mut asigned_tasks);
         while asigned_tasks.len() < instance.task_times().len() {
             self.add_task(&mut solution, instance, &mut asigned_tasks);
         }
@@ -26,32 +59,61 @@ impl ProblemSolver for RandomizedGreedySolver {
 impl RandomizedGreedySolver {
     /// Creates a new solver that will use the argument passed as k
     pub fn new(size_to_choose_from: usize) -> Self {
+        RandomizedGreedySolver::with_rcl(
+            RclPolicy::Cardinality(size_to_choose_from),
+            RclBias::Uniform,
+        )
+    }
+
+    /// Creates a new solver that builds the restricted candidate list with the policy and
+    /// chooses from it with the bias
+    pub fn with_rcl(rcl_policy: RclPolicy, bias: RclBias) -> Self {
+        RandomizedGreedySolver::with_seeding(rcl_policy, bias, Seeding::CheapestFirst)
+    }
+
+    /// Creates a new solver that builds the restricted candidate list with the policy,
+    /// chooses from it with the bias and chooses the first task of each machine with the
+    /// seeding
+    pub fn with_seeding(rcl_policy: RclPolicy, bias: RclBias, seeding: Seeding) -> Self {
+        match rcl_policy {
+            RclPolicy::Cardinality(size_to_choose_from) => assert!(size_to_choose_from > 0),
+            RclPolicy::Value(alpha) => assert!((0.0..=1.0).contains(&alpha)),
+        }
         RandomizedGreedySolver {
-            size_to_choose_from,
+            rcl_policy,
+            bias,
+            seeding,
         }
     }
 
-    fn choose_initial_tasks(
-        solution: &mut ProblemSolution,
+    /// Solves an instance applying the improvement to the partial solution each time the
+    /// specified number of tasks has been added. The improvement can move the tasks of the
+    /// partial solution but it can't add or remove them
+    pub(super) fn solve_with_improvement<F>(
+        &mut self,
         instance: &ProblemInstance,
-        asigned_tasks: &mut HashSet<usize>,
-    ) {
-        for _ in 0..instance.number_of_machines() {
-            let task = instance.setup_times()[0]
-                .iter()
-                .skip(1) // Skip the starting setup times column
-                .enumerate()
-                .filter(|(index, _)| !asigned_tasks.contains(index))
-                .map(|(index, setup)| (index, setup + instance.task_times()[index]))
-                .min_by_key(|(_, cost)| *cost);
-            let (task, tct_increment) = match task {
-                Some(values) => values,
-                None => break,
-            };
-            solution.task_assignment_matrix.push(vec![task]);
-            solution.tcts_by_machine.push(tct_increment);
-            asigned_tasks.insert(task);
+        period: usize,
+        improve: F,
+    ) -> ProblemSolution
+    where
+        F: Fn(ProblemSolution) -> ProblemSolution,
+    {
+        let mut asigned_tasks = HashSet::with_capacity(instance.task_times().len());
+        let mut solution = ProblemSolution {
+            tcts_by_machine: Vec::with_capacity(instance.number_of_machines()),
+            task_assignment_matrix: Vec::with_capacity(instance.number_of_machines()),
+        };
+        self.seeding
+            .seed(instance, &mut solution, &mut asigned_tasks);
+        let mut added_tasks = 0;
+        while asigned_tasks.len() < instance.task_times().len() {
+            self.add_task(&mut solution, instance, &mut asigned_tasks);
+            added_tasks += 1;
+            if added_tasks % period == 0 {
+                solution = improve(solution);
+            }
         }
+        solution
     }
 
     fn add_task(
@@ -61,14 +123,8 @@ impl RandomizedGreedySolver {
         asigned_tasks: &mut HashSet<usize>,
     ) {
         let possible_tasks = self.get_best_new_tasks(solution, instance, asigned_tasks);
-        let election = rand::random::<usize>() % possible_tasks.len();
-        solution.task_assignment_matrix[possible_tasks[election].machine].insert(
-            possible_tasks[election].position,
-            possible_tasks[election].task,
-        );
-        asigned_tasks.insert(possible_tasks[election].task);
-        solution.tcts_by_machine[possible_tasks[election].machine] +=
-            possible_tasks[election].tct_increment;
+        let election = self.bias.choose(possible_tasks.len());
+        GreedySolver::insert_task(solution, asigned_tasks, &possible_tasks[election]);
     }
 
     fn get_best_new_tasks(
@@ -77,42 +133,56 @@ impl RandomizedGreedySolver {
         instance: &ProblemInstance,
         asigned_tasks: &HashSet<usize>,
     ) -> Vec<NewTask> {
-        let mut new_tasks = (0..instance.number_of_machines())
-            .flat_map(move |machine| {
-                (0..instance.task_times().len())
-                    .filter(move |index| !asigned_tasks.contains(index))
-                    .flat_map(move |task| {
-                        (0..=solution.task_assignment_matrix[machine].len()).map(move |position| {
-                            RandomizedGreedySolver::get_new_solution(
-                                solution, instance, task, machine, position,
-                            )
-                        })
-                    })
-            })
-            .collect::<BinaryHeap<NewTask>>();
-        (0..self.size_to_choose_from)
-            .map(|_| new_tasks.pop())
-            .filter(|task| task.is_some())
-            .map(|task| task.unwrap())
-            .collect()
+        match self.rcl_policy {
+            RclPolicy::Cardinality(size_to_choose_from) => GreedySolver::get_best_new_tasks(
+                solution,
+                instance,
+                asigned_tasks,
+                size_to_choose_from,
+            ),
+            RclPolicy::Value(alpha) => {
+                let mut new_tasks = GreedySolver::get_new_tasks(solution, instance, asigned_tasks);
+                let min = new_tasks.peek().map_or(0, |task| task.tct_increment);
+                let max = new_tasks
+                    .iter()
+                    .map(|task| task.tct_increment)
+                    .max()
+                    .unwrap_or(0);
+                let threshold = min as f64 + alpha * (max - min) as f64;
+                let mut possible_tasks = Vec::new();
+                while let Some(task) = new_tasks.pop() {
+                    if task.tct_increment as f64 > threshold {
+                        break;
+                    }
+                    possible_tasks.push(task);
+                }
+                possible_tasks
+            }
+        }
     }
+}
 
-    fn get_new_solution(
-        solution: &ProblemSolution,
-        instance: &ProblemInstance,
-        task: usize,
-        machine: usize,
-        position: usize,
-    ) -> NewTask {
-        let mut task_list = solution.task_assignment_matrix[machine].clone();
-        task_list.insert(position, task);
-        let tct_increment = instance.calculate_total_completion_time(&task_list)
-            - solution.tcts_by_machine[machine];
-        NewTask {
-            task,
-            position,
-            machine,
-            tct_increment,
+impl RclBias {
+    /// Chooses the index of a candidate of a list sorted by rank
+    fn choose(&self, number_of_candidates: usize) -> usize {
+        let weights: Vec<f64> = (1..=number_of_candidates)
+            .map(|rank| {
+                let rank = rank as f64;
+                match self {
+                    RclBias::Uniform => 1.0,
+                    RclBias::Linear => 1.0 / rank,
+                    RclBias::Exponential => (-rank).exp(),
+                    RclBias::Logarithmic => 1.0 / (rank + 1.0).ln(),
+                }
+            })
+            .collect();
+        let mut value = rand::random::<f64>() * weights.iter().sum::<f64>();
+        for (index, weight) in weights.iter().enumerate() {
+            if value < *weight {
+                return index;
+            }
+            value -= weight;
         }
+        number_of_candidates - 1
     }
 }
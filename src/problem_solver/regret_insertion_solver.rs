@@ -0,0 +1,77 @@
+use super::{GreedySolver, NewTask, ProblemInstance, ProblemSolution, ProblemSolver, Seeding};
+use std::collections::HashSet;
+
+/// A regret insertion algorithm. In each step it evaluates the best position of every task
+/// that hasn't been assigned yet in each machine. The regret of a task is the difference
+/// between the TCT increment of its k-th best machine and its best machine, and the task
+/// with the largest regret is added in its best position, because it's the one that would
+/// lose more by waiting. Ties are broken by the lowest TCT increment. The first task of
+/// each machine is chosen with the [seeding](super::Seeding)
+pub struct RegretInsertionSolver {
+    k: usize,
+    seeding: Seeding,
+}
+
+impl ProblemSolver for RegretInsertionSolver {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut asigned_tasks = HashSet::with_capacity(instance.task_times().len());
+        let mut solution = ProblemSolution {
+            tcts_by_machine: Vec::with_capacity(instance.number_of_machines()),
+            task_assignment_matrix: Vec::with_capacity(instance.number_of_machines()),
+        };
+        self.seeding
+            .seed(instance, &mut solution, &mut asigned_tasks);
+        while asigned_tasks.len() < instance.task_times().len() {
+            let new_task = (0..instance.task_times().len())
+                .filter(|task| !asigned_tasks.contains(task))
+                .map(|task| self.get_regret(&solution, instance, task))
+                .max_by(|(regret, new_task), (other_regret, other_new_task)| {
+                    regret
+                        .cmp(other_regret)
+                        .then(other_new_task.tct_increment.cmp(&new_task.tct_increment))
+                })
+                .unwrap()
+                .1;
+            GreedySolver::insert_task(&mut solution, &mut asigned_tasks, &new_task);
+        }
+        solution
+    }
+}
+
+impl RegretInsertionSolver {
+    /// Creates a new solver that will use the k-th best machine to calculate the regret.
+    /// It must be at least 2. If there are less machines the worst one is used
+    pub fn new(k: usize) -> Self {
+        RegretInsertionSolver::with_seeding(k, Seeding::CheapestFirst)
+    }
+
+    /// Creates a new solver that will use the k-th best machine to calculate the regret
+    /// and chooses the first task of each machine with the seeding
+    pub fn with_seeding(k: usize, seeding: Seeding) -> Self {
+        assert!(k > 1);
+        RegretInsertionSolver { k, seeding }
+    }
+
+    /// Returns the regret of the task and its best insertion
+    fn get_regret(
+        &self,
+        solution: &ProblemSolution,
+        instance: &ProblemInstance,
+        task: usize,
+    ) -> (isize, NewTask) {
+        let mut best_by_machine: Vec<NewTask> = (0..solution.task_assignment_matrix.len())
+            .map(|machine| {
+                (0..=solution.task_assignment_matrix[machine].len())
+                    .map(|position| {
+                        GreedySolver::get_new_solution(solution, instance, task, machine, position)
+                    })
+                    .min_by_key(|new_task| new_task.tct_increment)
+                    .unwrap()
+            })
+            .collect();
+        best_by_machine.sort_by_key(|new_task| new_task.tct_increment);
+        let kth_best = best_by_machine[(self.k - 1).min(best_by_machine.len() - 1)].tct_increment;
+        let regret = kth_best - best_by_machine[0].tct_increment;
+        (regret, best_by_machine.swap_remove(0))
+    }
+}
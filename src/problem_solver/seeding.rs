@@ -0,0 +1,112 @@
+use super::{ProblemInstance, ProblemSolution};
+use std::collections::HashSet;
+
+/// The way in which the constructive solvers choose the first task of each machine, before
+/// adding the rest of the tasks
+#[derive(Clone, Debug, PartialEq)]
+pub enum Seeding {
+    /// Each machine gets the remaining task with the lowest setup time from the inactive
+    /// state plus processing time
+    CheapestFirst,
+    /// Each machine gets the remaining task with the highest processing time
+    LongestFirst,
+    /// The first machine gets the cheapest task and each of the next ones the task whose
+    /// lowest setup time with the chosen tasks, in any direction, is the highest
+    MaximallyDissimilar,
+    /// Each machine gets a random task
+    Random,
+    /// Each machine gets the task in the same position of the list, and the machines
+    /// without a task start empty. The list is checked against the instance when solving,
+    /// so the solver panics if there are more tasks than machines, a task that isn't in
+    /// the instance or a repeated task
+    UserSpecified(Vec<usize>),
+}
+
+impl Seeding {
+    /// Adds a machine to the solution for each machine of the instance with its initial task
+    pub(super) fn seed(
+        &self,
+        instance: &ProblemInstance,
+        solution: &mut ProblemSolution,
+        asigned_tasks: &mut HashSet<usize>,
+    ) {
+        let tasks = self.choose_tasks(instance);
+        for machine in 0..instance.number_of_machines() {
+            match tasks.get(machine) {
+                Some(&task) => {
+                    solution.task_assignment_matrix.push(vec![task]);
+                    solution
+                        .tcts_by_machine
+                        .push(instance.setup_times()[0][task + 1] + instance.task_times()[task]);
+                    asigned_tasks.insert(task);
+                }
+                None => {
+                    solution.task_assignment_matrix.push(Vec::new());
+                    solution.tcts_by_machine.push(0);
+                }
+            }
+        }
+    }
+
+    fn choose_tasks(&self, instance: &ProblemInstance) -> Vec<usize> {
+        let number_of_tasks = instance.task_times().len();
+        let number_of_seeds = instance.number_of_machines().min(number_of_tasks);
+        let cheapest_cost =
+            |task: &usize| instance.setup_times()[0][task + 1] + instance.task_times()[*task];
+        let mut tasks: Vec<usize> = (0..number_of_tasks).collect();
+        match self {
+            Seeding::CheapestFirst => {
+                tasks.sort_by_key(cheapest_cost);
+                tasks.truncate(number_of_seeds);
+                tasks
+            }
+            Seeding::LongestFirst => {
+                tasks.sort_by_key(|&task| std::cmp::Reverse(instance.task_times()[task]));
+                tasks.truncate(number_of_seeds);
+                tasks
+            }
+            Seeding::MaximallyDissimilar => {
+                let setup_times = instance.setup_times();
+                let mut seeds: Vec<usize> = tasks
+                    .iter()
+                    .copied()
+                    .min_by_key(cheapest_cost)
+                    .into_iter()
+                    .collect();
+                while seeds.len() < number_of_seeds {
+                    let task = tasks
+                        .iter()
+                        .copied()
+                        .filter(|task| !seeds.contains(task))
+                        .max_by_key(|&task| {
+                            seeds
+                                .iter()
+                                .map(|&seed| {
+                                    setup_times[seed + 1][task + 1]
+                                        .min(setup_times[task + 1][seed + 1])
+                                })
+                                .min()
+                                .unwrap()
+                        })
+                        .unwrap();
+                    seeds.push(task);
+                }
+                seeds
+            }
+            Seeding::Random => {
+                for index in 0..number_of_seeds {
+                    let chosen = index + rand::random::<usize>() % (number_of_tasks - index);
+                    tasks.swap(index, chosen);
+                }
+                tasks.truncate(number_of_seeds);
+                tasks
+            }
+            Seeding::UserSpecified(seeds) => {
+                assert!(seeds.len() <= instance.number_of_machines());
+                assert!(seeds.iter().all(|&task| task < number_of_tasks));
+                assert!(seeds.iter().collect::<HashSet<_>>().len() == seeds.len());
+                seeds.clone()
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use super::{
+    grasp::stop_criterion::StopCriterion, gvns::shake::Shake, ProblemInstance, ProblemSolution,
+    ProblemSolver,
+};
+
+/// A simulated annealing implementation. It builds a solution with a constructive solver
+/// and then it moves to random neighbours, which are generated applying one of the
+/// [shaking operators](super::gvns::shake::Shake) with strength 1. Better neighbours are
+/// always accepted and worse ones with a probability of e^(-delta / temperature). A fixed
+/// number of moves is tried with each temperature (the equilibrium length) and then the
+/// temperature is lowered with the cooling schedule. If the best solution doesn't improve
+/// during a number of temperatures the temperature can go back to the initial one. The stop
+/// criterion is checked after each temperature with the best TCT before and after it
+pub struct SimulatedAnnealing<S: StopCriterion> {
+    constructive_solver: Box<dyn ProblemSolver>,
+    moves: Vec<Box<dyn Shake>>,
+    cooling_schedule: CoolingSchedule,
+    initial_temperature: InitialTemperature,
+    equilibrium_length: usize,
+    reheat_after: Option<usize>,
+    stop_criterion: S,
+}
+
+/// The way in which the [SimulatedAnnealing](SimulatedAnnealing) lowers the temperature
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoolingSchedule {
+    /// Multiplies the temperature by the factor, that must be between 0 and 1
+    Geometric(f64),
+    /// Subtracts the value from the temperature, until it reaches 0
+    Linear(f64),
+    /// Lundy and Mees schedule, the new temperature is T / (1 + beta * T)
+    LundyMees(f64),
+}
+
+/// The way in which the [SimulatedAnnealing](SimulatedAnnealing) chooses the initial
+/// temperature
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InitialTemperature {
+    /// Uses the specified temperature
+    Fixed(f64),
+    /// Applies the specified number of random moves to the initial solution and chooses
+    /// the temperature that would accept the mean TCT increment with the specified
+    /// probability, that must be between 0 and 1
+    Calibrated {
+        samples: usize,
+        acceptance_probability: f64,
+    },
+}
+
+impl<S: StopCriterion> ProblemSolver for SimulatedAnnealing<S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut solution = self.constructive_solver.solve(instance);
+        let mut best_solution = solution.clone();
+        let initial_temperature = self.get_initial_temperature(instance, &solution);
+        let mut temperature = initial_temperature;
+        let mut temperatures_without_improvement = 0;
+        loop {
+            let previous_best_tct = best_solution.get_total_completion_time();
+            for _ in 0..self.equilibrium_length {
+                let new_solution = self.random_neighbour(instance, &solution);
+                let delta = new_solution.get_total_completion_time() as f64
+                    - solution.get_total_completion_time() as f64;
+                if delta <= 0.0
+                    || (temperature > 0.0 && rand::random::<f64>() < (-delta / temperature).exp())
+                {
+                    solution = new_solution;
+                    if solution.get_total_completion_time()
+                        < best_solution.get_total_completion_time()
+                    {
+                        best_solution = solution.clone();
+                    }
+                }
+            }
+            let best_tct = best_solution.get_total_completion_time();
+            if self.stop_criterion.stop(previous_best_tct, best_tct) {
+                return best_solution;
+            }
+            if best_tct < previous_best_tct {
+                temperatures_without_improvement = 0;
+            } else {
+                temperatures_without_improvement += 1;
+            }
+            match self.reheat_after {
+                Some(temperatures) if temperatures_without_improvement >= temperatures => {
+                    temperature = initial_temperature;
+                    temperatures_without_improvement = 0;
+                }
+                _ => temperature = self.cooling_schedule.cool(temperature),
+            }
+        }
+    }
+}
+
+impl<S: StopCriterion> SimulatedAnnealing<S> {
+    /// Creates a new instance with the specified arguments. There must be at least one
+    /// move and the equilibrium length must be greater than 0. If the number of
+    /// temperatures to reheat after is None it never reheats
+    pub fn new(
+        constructive_solver: Box<dyn ProblemSolver>,
+        moves: Vec<Box<dyn Shake>>,
+        cooling_schedule: CoolingSchedule,
+        initial_temperature: InitialTemperature,
+        equilibrium_length: usize,
+        reheat_after: Option<usize>,
+        stop_criterion: S,
+    ) -> Self {
+        assert!(!moves.is_empty());
+        assert!(equilibrium_length > 0);
+        match cooling_schedule {
+            CoolingSchedule::Geometric(factor) => assert!(factor > 0.0 && factor < 1.0),
+            CoolingSchedule::Linear(step) => assert!(step > 0.0),
+            CoolingSchedule::LundyMees(beta) => assert!(beta > 0.0),
+        }
+        match initial_temperature {
+            InitialTemperature::Fixed(temperature) => assert!(temperature >= 0.0),
+            InitialTemperature::Calibrated {
+                samples,
+                acceptance_probability,
+            } => {
+                assert!(samples > 0 && acceptance_probability > 0.0 && acceptance_probability < 1.0)
+            }
+        }
+        SimulatedAnnealing {
+            constructive_solver,
+            moves,
+            cooling_schedule,
+            initial_temperature,
+            equilibrium_length,
+            reheat_after,
+            stop_criterion,
+        }
+    }
+
+    fn random_neighbour(
+        &self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+    ) -> ProblemSolution {
+        self.moves[rand::random::<usize>() % self.moves.len()].shake(instance, solution.clone(), 1)
+    }
+
+    fn get_initial_temperature(
+        &self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+    ) -> f64 {
+        let (samples, acceptance_probability) = match self.initial_temperature {
+            InitialTemperature::Fixed(temperature) => return temperature,
+            InitialTemperature::Calibrated {
+                samples,
+                acceptance_probability,
+            } => (samples, acceptance_probability),
+        };
+        let increments: Vec<usize> = (0..samples)
+            .map(|_| {
+                self.random_neighbour(instance, solution)
+                    .get_total_completion_time()
+            })
+            .filter(|&tct| tct > solution.get_total_completion_time())
+            .map(|tct| tct - solution.get_total_completion_time())
+            .collect();
+        if increments.is_empty() {
+            return 1.0;
+        }
+        let mean_increment = increments.iter().sum::<usize>() as f64 / increments.len() as f64;
+        -mean_increment / acceptance_probability.ln()
+    }
+}
+
+impl CoolingSchedule {
+    fn cool(&self, temperature: f64) -> f64 {
+        match *self {
+            CoolingSchedule::Geometric(factor) => temperature * factor,
+            CoolingSchedule::Linear(step) => (temperature - step).max(0.0),
+            CoolingSchedule::LundyMees(beta) => temperature / (1.0 + beta * temperature),
+        }
+    }
+}
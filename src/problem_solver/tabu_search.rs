@@ -0,0 +1,230 @@
+use super::{
+    grasp::{local_search::Neighbourhood, stop_criterion::StopCriterion},
+    ProblemInstance, ProblemSolution, ProblemSolver,
+};
+
+/// A tabu search implementation. It builds a solution with a constructive solver and then
+/// it moves in each iteration to the best neighbour that isn't tabu, even if it's worse
+/// than the actual solution. The neighbours are generated with the
+/// [neighbourhoods](Neighbourhood), that is, the inter and intra machine reinsertions and
+/// swaps.
+///
+/// The tabu attributes are obtained comparing the actual solution with the chosen neighbour:
+/// * A task that leaves a machine can't go back to it
+/// * A task that stays in its machine but changes its predecessor can't be placed after
+///   the old predecessor again
+///
+/// Both last for the specified tenure. A tabu neighbour is accepted anyway if it's better
+/// than the best solution found (aspiration by objective). The stop criterion is checked
+/// after each iteration with the best TCT before and after it
+pub struct TabuSearch<S: StopCriterion> {
+    constructive_solver: Box<dyn ProblemSolver>,
+    neighbourhoods: Vec<Box<dyn Neighbourhood>>,
+    tenure: usize,
+    diversification: Option<Diversification>,
+    stop_criterion: S,
+}
+
+/// The diversification phase of the [TabuSearch](TabuSearch). It counts how many iterations
+/// each task has been assigned to each machine and, after some iterations without improving
+/// the best solution, penalizes during some iterations the neighbours that move tasks to the
+/// machines where they have been more often
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Diversification {
+    /// The number of iterations without improvement that start the phase
+    pub after: usize,
+    /// The number of iterations that the phase lasts
+    pub length: usize,
+    /// The penalty added to the TCT of a neighbour for each moved task, multiplied by the
+    /// fraction of the iterations that the task has been in its new machine
+    pub weight: f64,
+}
+
+/// The long-term and short-term memory of the [TabuSearch](TabuSearch)
+struct Memory {
+    iteration: usize,
+    machine_tabu: Vec<Vec<usize>>,
+    predecessor_tabu: Vec<Vec<usize>>,
+    frequency: Vec<Vec<usize>>,
+}
+
+impl<S: StopCriterion> ProblemSolver for TabuSearch<S> {
+    fn solve(&mut self, instance: &ProblemInstance) -> ProblemSolution {
+        let mut solution = self.constructive_solver.solve(instance);
+        let mut best_solution = solution.clone();
+        let mut memory = Memory::new(instance);
+        let mut iterations_without_improvement = 0;
+        let mut diversifying_until = 0;
+        loop {
+            memory.iteration += 1;
+            let previous_best_tct = best_solution.get_total_completion_time();
+            let positions = get_positions(&solution);
+            let diversifying = memory.iteration <= diversifying_until;
+            let new_solution = match self.best_admissible_neighbour(
+                instance,
+                &solution,
+                &positions,
+                &memory,
+                previous_best_tct,
+                diversifying,
+            ) {
+                Some(new_solution) => new_solution,
+                None => return best_solution,
+            };
+            memory.update(self.tenure, &positions, &new_solution);
+            solution = new_solution;
+            if solution.get_total_completion_time() < previous_best_tct {
+                best_solution = solution.clone();
+                iterations_without_improvement = 0;
+            } else {
+                iterations_without_improvement += 1;
+            }
+            if let Some(diversification) = self.diversification {
+                if !diversifying && iterations_without_improvement >= diversification.after {
+                    diversifying_until = memory.iteration + diversification.length;
+                    iterations_without_improvement = 0;
+                }
+            }
+            if self
+                .stop_criterion
+                .stop(previous_best_tct, best_solution.get_total_completion_time())
+            {
+                return best_solution;
+            }
+        }
+    }
+}
+
+impl<S: StopCriterion> TabuSearch<S> {
+    /// Creates a new instance with the specified arguments. There must be at least one
+    /// neighbourhood and the tenure must be greater than 0. If the diversification is None
+    /// the search never diversifies
+    pub fn new(
+        constructive_solver: Box<dyn ProblemSolver>,
+        neighbourhoods: Vec<Box<dyn Neighbourhood>>,
+        tenure: usize,
+        diversification: Option<Diversification>,
+        stop_criterion: S,
+    ) -> Self {
+        assert!(!neighbourhoods.is_empty());
+        assert!(tenure > 0);
+        if let Some(diversification) = diversification {
+            assert!(diversification.after > 0 && diversification.weight >= 0.0);
+        }
+        TabuSearch {
+            constructive_solver,
+            neighbourhoods,
+            tenure,
+            diversification,
+            stop_criterion,
+        }
+    }
+
+    fn best_admissible_neighbour(
+        &self,
+        instance: &ProblemInstance,
+        solution: &ProblemSolution,
+        positions: &[(usize, usize)],
+        memory: &Memory,
+        best_tct: usize,
+        diversifying: bool,
+    ) -> Option<ProblemSolution> {
+        let mut best_neighbour: Option<(f64, ProblemSolution)> = None;
+        for neighbour in self
+            .neighbourhoods
+            .iter()
+            .flat_map(|neighbourhood| neighbourhood.neighbours(instance, solution))
+        {
+            let new_positions = get_positions(&neighbour);
+            let tct = neighbour.get_total_completion_time();
+            if tct >= best_tct && memory.is_tabu(positions, &new_positions) {
+                continue;
+            }
+            let mut score = tct as f64;
+            if diversifying {
+                score += self.diversification.unwrap().weight
+                    * memory.frequency_penalty(positions, &new_positions);
+            }
+            if best_neighbour
+                .as_ref()
+                .is_none_or(|(best_score, _)| score < *best_score)
+            {
+                best_neighbour = Some((score, neighbour));
+            }
+        }
+        best_neighbour.map(|(_, neighbour)| neighbour)
+    }
+}
+
+impl Memory {
+    fn new(instance: &ProblemInstance) -> Self {
+        let number_of_tasks = instance.task_times().len();
+        let number_of_machines = instance.number_of_machines();
+        Memory {
+            iteration: 0,
+            machine_tabu: vec![vec![0; number_of_machines]; number_of_tasks],
+            predecessor_tabu: vec![vec![0; number_of_tasks + 1]; number_of_tasks],
+            frequency: vec![vec![0; number_of_machines]; number_of_tasks],
+        }
+    }
+
+    fn is_tabu(&self, positions: &[(usize, usize)], new_positions: &[(usize, usize)]) -> bool {
+        positions.iter().zip(new_positions).enumerate().any(
+            |(task, (&(machine, predecessor), &(new_machine, new_predecessor)))| {
+                if machine != new_machine {
+                    self.machine_tabu[task][new_machine] >= self.iteration
+                } else {
+                    predecessor != new_predecessor
+                        && self.predecessor_tabu[task][new_predecessor] >= self.iteration
+                }
+            },
+        )
+    }
+
+    fn frequency_penalty(
+        &self,
+        positions: &[(usize, usize)],
+        new_positions: &[(usize, usize)],
+    ) -> f64 {
+        positions
+            .iter()
+            .zip(new_positions)
+            .enumerate()
+            .filter(|(_, ((machine, _), (new_machine, _)))| machine != new_machine)
+            .map(|(task, (_, &(new_machine, _)))| {
+                self.frequency[task][new_machine] as f64 / self.iteration as f64
+            })
+            .sum()
+    }
+
+    /// Makes tabu the attributes that the new solution has removed and adds its
+    /// assignments to the frequency memory
+    fn update(&mut self, tenure: usize, positions: &[(usize, usize)], solution: &ProblemSolution) {
+        let new_positions = get_positions(solution);
+        for (task, (&(machine, predecessor), &(new_machine, new_predecessor))) in
+            positions.iter().zip(&new_positions).enumerate()
+        {
+            if machine != new_machine {
+                self.machine_tabu[task][machine] = self.iteration + tenure;
+            } else if predecessor != new_predecessor {
+                self.predecessor_tabu[task][predecessor] = self.iteration + tenure;
+            }
+            self.frequency[task][new_machine] += 1;
+        }
+    }
+}
+
+/// Returns the machine of each task and its predecessor, that is 0 for the first task
+/// of a machine and the task + 1 otherwise, as in the setup times matrix
+fn get_positions(solution: &ProblemSolution) -> Vec<(usize, usize)> {
+    let number_of_tasks = solution.task_assignment_matrix.iter().map(Vec::len).sum();
+    let mut positions = vec![(0, 0); number_of_tasks];
+    for (machine, tasks) in solution.task_assignment_matrix.iter().enumerate() {
+        let mut predecessor = 0;
+        for &task in tasks {
+            positions[task] = (machine, predecessor);
+            predecessor = task + 1;
+        }
+    }
+    positions
+}